@@ -0,0 +1,33 @@
+//! Benchmarks for deserializing extended public keys.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use kms::bip32::{ExtendedKey, XPub};
+
+const XPUB_BASE58: &str = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhe\
+                           PY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8";
+
+fn raw_bytes() -> [u8; ExtendedKey::BYTE_SIZE] {
+    let mut bytes = [0u8; ExtendedKey::BYTE_SIZE + 4];
+    bs58::decode(XPUB_BASE58)
+        .with_check(None)
+        .into(&mut bytes)
+        .unwrap();
+
+    bytes[..ExtendedKey::BYTE_SIZE].try_into().unwrap()
+}
+
+fn deserialize(c: &mut Criterion) {
+    let raw = raw_bytes();
+
+    let mut group = c.benchmark_group("xpub_deserialize");
+    group.bench_function("from_str", |b| {
+        b.iter(|| black_box(XPUB_BASE58).parse::<XPub>().unwrap())
+    });
+    group.bench_function("from_raw_bytes", |b| {
+        b.iter(|| XPub::from_raw_bytes(black_box(&raw)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, deserialize);
+criterion_main!(benches);
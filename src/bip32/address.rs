@@ -0,0 +1,236 @@
+//! Address encodings for public keys
+
+use crate::bip32::PublicKeyBytes;
+use alloc::{string::String, vec::Vec};
+use core::iter;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+/// `RIPEMD160(SHA256(data))`, as used by Bitcoin-family addresses.
+pub(crate) fn hash160(data: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(Sha256::digest(data)).into()
+}
+
+/// P2PKH version byte for the given SLIP-44 coin type, if known.
+pub(crate) fn p2pkh_version(coin: u32) -> Option<u8> {
+    match coin {
+        // Bitcoin
+        0 => Some(0x00),
+        // Testnet (all coins)
+        1 => Some(0x6f),
+        // Litecoin
+        2 => Some(0x30),
+        // Dogecoin
+        3 => Some(0x1e),
+        _ => None,
+    }
+}
+
+/// Bech32 alphabet (BIP173).
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Checksum constant for Bech32 (BIP173), used by witness version 0.
+const BECH32_CONST: u32 = 1;
+
+/// Checksum constant for Bech32m (BIP350), used by witness versions 1+.
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+/// Bitcoin addresses of every standard single-key type for one public key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BitcoinAddresses {
+    /// Legacy pay-to-public-key-hash address (`1...`).
+    pub p2pkh: String,
+
+    /// Pay-to-witness-public-key-hash nested in pay-to-script-hash (`3...`).
+    pub p2sh_p2wpkh: String,
+
+    /// Native SegWit pay-to-witness-public-key-hash address (`bc1q...`).
+    pub p2wpkh: String,
+
+    /// BIP86 pay-to-taproot address with no script path (`bc1p...`).
+    pub p2tr: String,
+}
+
+impl BitcoinAddresses {
+    /// Compute all address types for a compressed public key.
+    pub(crate) fn new(public_key: &PublicKeyBytes, mainnet: bool) -> Self {
+        let (p2pkh_version, p2sh_version, hrp) = if mainnet {
+            (0x00, 0x05, "bc")
+        } else {
+            (0x6f, 0xc4, "tb")
+        };
+
+        let key_hash = hash160(public_key);
+        let redeem_script = p2wpkh_script(public_key);
+
+        BitcoinAddresses {
+            p2pkh: p2pkh(p2pkh_version, public_key),
+            p2sh_p2wpkh: base58check_hash160(p2sh_version, &hash160(&redeem_script)),
+            p2wpkh: segwit(hrp, 0, &key_hash),
+            p2tr: segwit(hrp, 1, &taproot_output_key(public_key)),
+        }
+    }
+}
+
+/// Base58Check-encode a short (address-sized) payload.
+fn base58check(payload: &[u8]) -> String {
+    let mut buffer = [0u8; 64];
+    let len = bs58::encode(payload)
+        .with_check()
+        .into(buffer.as_mut())
+        .expect("address payload fits in buffer");
+
+    String::from_utf8(buffer[..len].to_vec()).expect("base58 is ASCII")
+}
+
+/// Base58Check-encode a version byte followed by a 20-byte hash.
+fn base58check_hash160(version: u8, hash: &[u8; 20]) -> String {
+    let mut payload = [0u8; 21];
+    payload[0] = version;
+    payload[1..].copy_from_slice(hash);
+
+    base58check(&payload)
+}
+
+/// SegWit v0 pay-to-witness-public-key-hash script: `OP_0 <hash160>`.
+pub(crate) fn p2wpkh_script(public_key: &PublicKeyBytes) -> [u8; 22] {
+    let mut script = [0u8; 22];
+    script[1] = 0x14;
+    script[2..].copy_from_slice(&hash160(public_key));
+    script
+}
+
+/// Base58Check-encoded pay-to-public-key-hash address.
+pub(crate) fn p2pkh(version: u8, public_key: &PublicKeyBytes) -> String {
+    base58check_hash160(version, &hash160(public_key))
+}
+
+/// Bech32 checksum polynomial (BIP173).
+fn bech32_polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    values.fold(1, |checksum, value| {
+        let top = checksum >> 25;
+        let checksum = ((checksum & 0x1ffffff) << 5) ^ value as u32;
+
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(checksum, |checksum, (_, generator)| checksum ^ generator)
+    })
+}
+
+/// Bech32 (version 0) or Bech32m (version 1+) encoded SegWit address.
+pub(crate) fn segwit(hrp: &str, witness_version: u8, program: &[u8]) -> String {
+    let mut data = Vec::with_capacity(1 + (program.len() * 8).div_ceil(5) + 6);
+    data.push(witness_version);
+
+    // Regroup 8-bit bytes into 5-bit values, padding the last one with zeros
+    let mut acc = 0u32;
+    let mut bits = 0;
+    for byte in program {
+        acc = ((acc << 8) | *byte as u32) & 0xfff;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            data.push(((acc >> bits) & 31) as u8);
+        }
+    }
+    if bits > 0 {
+        data.push(((acc << (5 - bits)) & 31) as u8);
+    }
+
+    let constant = if witness_version == 0 {
+        BECH32_CONST
+    } else {
+        BECH32M_CONST
+    };
+    let checksum = bech32_polymod(
+        hrp.bytes()
+            .map(|c| c >> 5)
+            .chain(iter::once(0))
+            .chain(hrp.bytes().map(|c| c & 31))
+            .chain(data.iter().copied())
+            .chain([0; 6]),
+    ) ^ constant;
+    data.extend((0..6).map(|i| ((checksum >> (5 * (5 - i))) & 31) as u8));
+
+    let mut address = String::with_capacity(hrp.len() + 1 + data.len());
+    address.push_str(hrp);
+    address.push('1');
+    address.extend(data.iter().map(|d| BECH32_CHARSET[*d as usize] as char));
+    address
+}
+
+/// BIP86 taproot output key: the x-only public key tweaked with
+/// `hash_TapTweak(x)`, committing to no script path.
+fn taproot_output_key(public_key: &PublicKeyBytes) -> [u8; 32] {
+    let x_only = &public_key[1..];
+    let tag = Sha256::digest(b"TapTweak");
+    let tweak = Sha256::new()
+        .chain_update(tag)
+        .chain_update(tag)
+        .chain_update(x_only)
+        .finalize();
+
+    // BIP340 keys are implicitly the point with an even Y coordinate
+    let mut even_key = [0u8; 33];
+    even_key[0] = 0x02;
+    even_key[1..].copy_from_slice(x_only);
+
+    let mut output_key = libsecp256k1::PublicKey::parse_compressed(&even_key)
+        .expect("x coordinate of a valid public key");
+
+    // A tweak outside the curve order, or one cancelling the key, has
+    // negligible probability (~2^-128)
+    output_key
+        .tweak_add_assign(
+            &libsecp256k1::SecretKey::parse(&tweak.into()).expect("tweak below curve order"),
+        )
+        .expect("tweaked key is not infinity");
+
+    output_key.serialize_compressed()[1..]
+        .try_into()
+        .expect("x coordinate is 32 bytes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{p2pkh, p2pkh_version, BitcoinAddresses};
+    use hex_literal::hex;
+
+    #[test]
+    fn p2pkh_address() {
+        // BIP32 test vector 1, chain m
+        let public_key = hex!("0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2");
+        assert_eq!(
+            p2pkh(p2pkh_version(0).unwrap(), &public_key),
+            "15mKKb2eos1hWa6tisdPwwDC1a5J1y9nma"
+        );
+    }
+
+    #[test]
+    fn bitcoin_addresses() {
+        // "abandon ... about", BIP84 m/84'/0'/0'/0/0
+        let public_key = hex!("0330d54fd0dd420a6e5f8d3624f5f3482cae350f79d5f0753bf5beef9c2d91af3c");
+        assert_eq!(
+            BitcoinAddresses::new(&public_key, true).p2wpkh,
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+
+        // "abandon ... about", BIP49 m/49'/1'/0'/0/0 (testnet)
+        let public_key = hex!("03a1af804ac108a8a51782198c2d034b28bf90c8803f5a53f76276fa69a4eae77f");
+        assert_eq!(
+            BitcoinAddresses::new(&public_key, false).p2sh_p2wpkh,
+            "2Mww8dCYPUpKHofjgcXcBCEGmniw9CoaiD2"
+        );
+
+        // "abandon ... about", BIP86 m/86'/0'/0'/0/0 (parity of Y is ignored)
+        let public_key = hex!("02cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115");
+        assert_eq!(
+            BitcoinAddresses::new(&public_key, true).p2tr,
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Chain codes
+
+use crate::bip32::{Error, Result, KEY_SIZE};
+use core::fmt;
+use zeroize::Zeroize;
+
+/// Chain code: extension for both private and public keys which provides an
+/// additional 256-bits of entropy.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct ChainCode([u8; KEY_SIZE]);
+
+impl ChainCode {
+    /// Parse a chain code from the byte encoding.
+    pub fn from_bytes(bytes: [u8; KEY_SIZE]) -> Self {
+        Self(bytes)
+    }
+
+    /// Borrow the byte encoding of this chain code.
+    pub fn as_bytes(&self) -> &[u8; KEY_SIZE] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for ChainCode {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; KEY_SIZE]> for ChainCode {
+    fn from(bytes: [u8; KEY_SIZE]) -> ChainCode {
+        ChainCode(bytes)
+    }
+}
+
+impl From<ChainCode> for [u8; KEY_SIZE] {
+    fn from(chain_code: ChainCode) -> [u8; KEY_SIZE] {
+        chain_code.0
+    }
+}
+
+impl TryFrom<&[u8]> for ChainCode {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<ChainCode> {
+        Ok(ChainCode(bytes.try_into()?))
+    }
+}
+
+impl Zeroize for ChainCode {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::LowerHex for ChainCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChainCode;
+    use crate::bip32::Error;
+    use hex_literal::hex;
+
+    #[test]
+    fn lower_hex() {
+        let chain_code = ChainCode::from_bytes(hex!(
+            "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508"
+        ));
+        assert_eq!(
+            format!("{:x}", chain_code),
+            "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508"
+        );
+    }
+
+    #[test]
+    fn try_from_slice() {
+        assert_eq!(
+            ChainCode::try_from(&[7u8; 32][..]),
+            Ok(ChainCode::from_bytes([7u8; 32]))
+        );
+        assert_eq!(ChainCode::try_from(&[7u8; 31][..]), Err(Error::Decode));
+    }
+}
@@ -7,7 +7,7 @@ use core::{
 };
 
 /// Index of a particular child key for a given (extended) private key.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct ChildNumber(pub u32);
 
 impl ChildNumber {
@@ -30,6 +30,13 @@ impl ChildNumber {
         }
     }
 
+    /// Create new non-hardened [`ChildNumber`] with the given index.
+    ///
+    /// Returns an error if it is equal to or greater than [`Self::HARDENED_FLAG`].
+    pub fn normal(index: u32) -> Result<Self> {
+        Self::new(index, false)
+    }
+
     /// Parse a child number from the byte encoding.
     pub fn from_bytes(bytes: [u8; Self::BYTE_SIZE]) -> Self {
         u32::from_be_bytes(bytes).into()
@@ -86,7 +93,12 @@ impl FromStr for ChildNumber {
         };
 
         let index = child.parse().map_err(|_| Error::ChildNumber)?;
-        ChildNumber::new(index, hardened)
+
+        if hardened {
+            ChildNumber::new(index, true)
+        } else {
+            ChildNumber::normal(index)
+        }
     }
 }
 
@@ -122,5 +134,7 @@ mod tests {
         let index = ChildNumber::HARDENED_FLAG;
         assert_eq!(ChildNumber::new(index, false), Err(Error::ChildNumber));
         assert_eq!(ChildNumber::new(index, true), Err(Error::ChildNumber));
+        assert_eq!(ChildNumber::normal(index), Err(Error::ChildNumber));
+        assert_eq!(ChildNumber::normal(index - 1), Ok(ChildNumber(index - 1)));
     }
 }
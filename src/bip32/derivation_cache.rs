@@ -0,0 +1,123 @@
+//! Derivation caching
+
+use crate::bip32::{DerivationPath, PublicKeyBytes, Result, XPrv};
+use std::collections::HashMap;
+use zeroize::Zeroize;
+
+/// Cache of [`XPrv`] keys derived from a root key, keyed by [`DerivationPath`].
+///
+/// Avoids re-deriving intermediate keys (e.g. the account key `m/44'/60'/0'`)
+/// on every signing request. A cache serves one root key at a time: passing a
+/// different root clears it. Cached keys are zeroized when they are evicted
+/// or the cache is dropped.
+#[derive(Default)]
+pub struct DerivationCache {
+    /// Public key of the root key the cached keys were derived from.
+    root: Option<PublicKeyBytes>,
+
+    /// Cached keys.
+    keys: HashMap<DerivationPath, XPrv>,
+}
+
+impl DerivationCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the key at `path` below `root`, deriving and caching it if needed.
+    ///
+    /// Derivation starts from the deepest cached ancestor of `path`.
+    pub fn get_or_derive(&mut self, root: &XPrv, path: &DerivationPath) -> Result<&XPrv> {
+        let root_public_key = root.public_key().to_bytes();
+
+        if self.root != Some(root_public_key) {
+            self.clear();
+            self.root = Some(root_public_key);
+        }
+
+        if !self.keys.contains_key(path) {
+            let key = self.derive(root, path)?;
+            self.keys.insert(path.clone(), key);
+        }
+
+        Ok(&self.keys[path])
+    }
+
+    /// Number of cached keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Is the cache empty?
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Zeroize and remove all cached keys.
+    pub fn clear(&mut self) {
+        for key in self.keys.values_mut() {
+            key.zeroize();
+        }
+
+        self.keys.clear();
+        self.root = None;
+    }
+
+    /// Derive the key at `path`, starting from its deepest cached ancestor.
+    fn derive(&self, root: &XPrv, path: &DerivationPath) -> Result<XPrv> {
+        let mut ancestor = path.parent();
+
+        while let Some(parent) = ancestor {
+            if let Some(key) = self.keys.get(&parent) {
+                return path
+                    .iter()
+                    .skip(parent.len())
+                    .try_fold(key.clone(), |key, child| key.derive_child(child));
+            }
+
+            ancestor = parent.parent();
+        }
+
+        root.clone().derive_from_path(path)
+    }
+}
+
+impl Drop for DerivationCache {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DerivationCache;
+    use crate::bip32::{DerivationPath, XPrv};
+    use hex_literal::hex;
+
+    const SEED: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+
+    #[test]
+    fn get_or_derive() {
+        let root = XPrv::new(SEED).unwrap();
+        let mut cache = DerivationCache::new();
+
+        let account: DerivationPath = "m/44'/60'/0'".parse().unwrap();
+        let address: DerivationPath = "m/44'/60'/0'/0/7".parse().unwrap();
+
+        let expected = XPrv::new_from_path(SEED, &account).unwrap();
+        assert_eq!(cache.get_or_derive(&root, &account).unwrap(), &expected);
+
+        let expected = XPrv::new_from_path(SEED, &address).unwrap();
+        assert_eq!(cache.get_or_derive(&root, &address).unwrap(), &expected);
+        assert_eq!(cache.len(), 2);
+
+        let other_root = XPrv::new([1u8; 16]).unwrap();
+        let expected = XPrv::new_from_path([1u8; 16], &address).unwrap();
+        assert_eq!(
+            cache.get_or_derive(&other_root, &address).unwrap(),
+            &expected
+        );
+        assert_eq!(cache.len(), 1);
+    }
+}
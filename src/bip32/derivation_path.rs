@@ -1,6 +1,8 @@
 //! Derivation paths
 
-use crate::bip32::{ChildNumber, Error, Result};
+use crate::bip32::{
+    extended_key::slip132_version, ChildNumber, Error, NetworkKind, Result, ScriptType, Version,
+};
 use alloc::vec::{self, Vec};
 use core::{
     fmt::{self, Display},
@@ -10,14 +12,146 @@ use core::{
 /// Prefix for all derivation paths.
 const PREFIX: &str = "m";
 
+/// Parse a [`DerivationPath`] from a string literal, checking it at compile time.
+///
+/// Invalid paths are rejected with a compile error, so no runtime error
+/// handling is needed for hardcoded paths:
+///
+/// ```
+/// use kms::bip32::DerivationPath;
+///
+/// let path = kms::bip32_path!("m/44'/60'/0'/0/0");
+/// assert_eq!(path, "m/44'/60'/0'/0/0".parse::<DerivationPath>().unwrap());
+/// ```
+#[macro_export]
+macro_rules! bip32_path {
+    ($path:literal) => {{
+        const _: () = assert!(
+            $crate::bip32::DerivationPath::is_valid($path),
+            "invalid BIP32 derivation path"
+        );
+        $path
+            .parse::<$crate::bip32::DerivationPath>()
+            .expect("derivation path checked at compile time")
+    }};
+}
+
 /// Derivation paths within a hierarchical keyspace.
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct DerivationPath {
     path: Vec<ChildNumber>,
 }
 
 impl DerivationPath {
+    /// Is the given string a well-formed derivation path?
+    ///
+    /// This is a `const fn` so paths can be checked at compile time, see
+    /// [`bip32_path!`](crate::bip32_path).
+    pub const fn is_valid(path: &str) -> bool {
+        let bytes = path.as_bytes();
+
+        if bytes.is_empty() || bytes[0] != PREFIX.as_bytes()[0] {
+            return false;
+        }
+
+        let mut i = 1;
+
+        while i < bytes.len() {
+            if bytes[i] != b'/' {
+                return false;
+            }
+            i += 1;
+
+            let start = i;
+            let mut index: u64 = 0;
+
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                index = index * 10 + (bytes[i] - b'0') as u64;
+
+                if index >= ChildNumber::HARDENED_FLAG as u64 {
+                    return false;
+                }
+                i += 1;
+            }
+
+            if i == start {
+                return false;
+            }
+
+            if i < bytes.len() && bytes[i] == b'\'' {
+                i += 1;
+            }
+        }
+
+        true
+    }
+
+    /// Construct the BIP44 path `m/44'/{coin}'/{account}'/{change}/{index}`.
+    ///
+    /// Returns [`Error::ChildNumber`] if `change` is not `0` (external) or
+    /// `1` (internal), or if any other component is `2^31` or greater.
+    pub fn from_bip44_components(coin: u32, account: u32, change: u32, index: u32) -> Result<Self> {
+        if change > 1 {
+            return Err(Error::ChildNumber);
+        }
+
+        Ok(DerivationPath {
+            path: vec![
+                ChildNumber::new(44, true)?,
+                ChildNumber::new(coin, true)?,
+                ChildNumber::new(account, true)?,
+                ChildNumber::normal(change)?,
+                ChildNumber::normal(index)?,
+            ],
+        })
+    }
+
+    /// Parse a derivation path from its compact binary encoding, as sent to
+    /// e.g. hardware wallets: one 4-byte little endian [`ChildNumber`] per
+    /// component, with the most significant bit as the hardened flag.
+    ///
+    /// Returns [`Error::Decode`] if the length is not a multiple of 4.
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self> {
+        let chunks = bytes.chunks_exact(ChildNumber::BYTE_SIZE);
+
+        if !chunks.remainder().is_empty() {
+            return Err(Error::Decode);
+        }
+
+        Ok(DerivationPath {
+            path: chunks
+                .map(|chunk| ChildNumber(u32::from_le_bytes(chunk.try_into().unwrap())))
+                .collect(),
+        })
+    }
+
+    /// Serialize this derivation path in the compact binary encoding read by
+    /// [`DerivationPath::from_compact_bytes`].
+    pub fn to_compact_bytes(&self) -> Vec<u8> {
+        self.path
+            .iter()
+            .flat_map(|child_number| child_number.0.to_le_bytes())
+            .collect()
+    }
+
+    /// Get the SLIP-132 public key [`Version`] for accounts under this path,
+    /// detected from its hardened purpose component: `xpub` for BIP44,
+    /// `ypub` for BIP49 and `zpub` for BIP84, or their testnet equivalents.
+    ///
+    /// BIP86 (taproot) keys have no SLIP-132 version of their own and use
+    /// `xpub`/`tpub`. Returns `None` for any other purpose.
+    pub fn to_slip132_version(&self, network: NetworkKind) -> Option<Version> {
+        let script_type = match self.path.first().filter(|c| c.is_hardened())?.index() {
+            44 | 86 => ScriptType::P2PKH,
+            49 => ScriptType::P2SH_P2WPKH,
+            84 => ScriptType::P2WPKH,
+            _ => return None,
+        };
+
+        slip132_version(script_type, network, false)
+    }
+
     /// Iterate over the [`ChildNumber`] values in this derivation path.
     pub fn iter(&self) -> impl Iterator<Item = ChildNumber> + '_ {
         self.path.iter().cloned()
@@ -33,6 +167,50 @@ impl DerivationPath {
         self.path.len()
     }
 
+    /// Get the purpose (first component) of this path, if it is hardened as
+    /// in BIP43, e.g. `44` for `m/44'/60'/0'/0/0`.
+    pub fn purpose(&self) -> Option<u32> {
+        self.path
+            .first()
+            .filter(|child_number| child_number.is_hardened())
+            .map(|child_number| child_number.index())
+    }
+
+    /// Get the coin type of this BIP44 path, e.g. `60` for
+    /// `m/44'/60'/0'/0/0`.
+    ///
+    /// Returns `None` unless the path has at least 3 components, the
+    /// first 3 of which are hardened, and its purpose is 44.
+    pub fn coin_type(&self) -> Option<u32> {
+        self.bip44_component(1)
+    }
+
+    /// Get the account index of this BIP44 path, e.g. `0` for
+    /// `m/44'/60'/0'/0/0`.
+    ///
+    /// Returns `None` in the same cases as [`DerivationPath::coin_type`].
+    pub fn account_index(&self) -> Option<u32> {
+        self.bip44_component(2)
+    }
+
+    /// Get one of the hardened levels of a BIP44 path.
+    fn bip44_component(&self, level: usize) -> Option<u32> {
+        if self.purpose() != Some(44) || self.hardened_depth() < 3 {
+            return None;
+        }
+
+        Some(self.path[level].index())
+    }
+
+    /// Get the number of leading consecutive hardened [`ChildNumber`] values
+    /// in this derivation path, e.g. 3 for `m/44'/60'/0'/0/0`.
+    pub fn hardened_depth(&self) -> usize {
+        self.path
+            .iter()
+            .take_while(|child_number| child_number.is_hardened())
+            .count()
+    }
+
     /// Get the parent [`DerivationPath`] for the current one.
     ///
     /// Returns `None` if this is already the root path.
@@ -105,6 +283,7 @@ impl IntoIterator for DerivationPath {
 #[cfg(test)]
 mod tests {
     use super::DerivationPath;
+    use crate::bip32::{Error, NetworkKind};
     use alloc::string::ToString;
 
     /// BIP32 test vectors
@@ -160,6 +339,137 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_valid() {
+        assert!(DerivationPath::is_valid("m"));
+        assert!(DerivationPath::is_valid("m/44'/60'/0'/0/0"));
+        assert!(DerivationPath::is_valid("m/0/2147483647'"));
+
+        assert!(!DerivationPath::is_valid(""));
+        assert!(!DerivationPath::is_valid("m/"));
+        assert!(!DerivationPath::is_valid("n/0"));
+        assert!(!DerivationPath::is_valid("m/0''"));
+        assert!(!DerivationPath::is_valid("m/a"));
+        assert!(!DerivationPath::is_valid("m/2147483648"));
+    }
+
+    #[test]
+    fn from_bip44_components() {
+        assert_eq!(
+            DerivationPath::from_bip44_components(60, 0, 1, 7),
+            "m/44'/60'/0'/1/7".parse()
+        );
+
+        assert_eq!(
+            DerivationPath::from_bip44_components(0, 0, 2, 0),
+            Err(Error::ChildNumber)
+        );
+        assert_eq!(
+            DerivationPath::from_bip44_components(0, 1 << 31, 0, 0),
+            Err(Error::ChildNumber)
+        );
+        assert_eq!(
+            DerivationPath::from_bip44_components(0, 0, 0, 1 << 31),
+            Err(Error::ChildNumber)
+        );
+    }
+
+    #[test]
+    fn compact_bytes_round_trip() {
+        let path: DerivationPath = "m/44'/60'/0'/0/7".parse().unwrap();
+        let bytes = path.to_compact_bytes();
+
+        assert_eq!(bytes.len(), 20);
+        assert_eq!(&bytes[..4], &[44, 0, 0, 0x80]);
+        assert_eq!(&bytes[16..], &[7, 0, 0, 0]);
+        assert_eq!(DerivationPath::from_compact_bytes(&bytes), Ok(path));
+
+        assert_eq!(
+            DerivationPath::from_compact_bytes(&[]),
+            Ok(DerivationPath::default())
+        );
+        assert_eq!(
+            DerivationPath::from_compact_bytes(&bytes[..19]),
+            Err(Error::Decode)
+        );
+    }
+
+    #[test]
+    fn bip32_path_macro() {
+        let path = crate::bip32_path!("m/44'/60'/0'/0/0");
+        assert_eq!(path, "m/44'/60'/0'/0/0".parse::<DerivationPath>().unwrap());
+    }
+
+    #[test]
+    fn to_slip132_version() {
+        let version = |path: &str, network| {
+            path.parse::<DerivationPath>()
+                .unwrap()
+                .to_slip132_version(network)
+        };
+
+        assert_eq!(version("m/44'/0'/0'", NetworkKind::Main), Some(0x0488b21e));
+        assert_eq!(version("m/49'/0'/0'", NetworkKind::Main), Some(0x049d7cb2));
+        assert_eq!(version("m/84'/0'/0'", NetworkKind::Main), Some(0x04b24746));
+        assert_eq!(version("m/86'/0'/0'", NetworkKind::Main), Some(0x0488b21e));
+        assert_eq!(version("m/44'/1'/0'", NetworkKind::Test), Some(0x043587cf));
+        assert_eq!(version("m/49'/1'/0'", NetworkKind::Test), Some(0x044a5262));
+        assert_eq!(version("m/84'/1'/0'", NetworkKind::Test), Some(0x045f1cf6));
+        assert_eq!(version("m/45'/0'/0'", NetworkKind::Main), None);
+        assert_eq!(version("m/44/0'/0'", NetworkKind::Main), None);
+        assert_eq!(version("m", NetworkKind::Main), None);
+    }
+
+    #[test]
+    fn parse_rejects_index_overflow() {
+        assert_eq!(
+            "m/2147483648".parse::<DerivationPath>(),
+            Err(Error::ChildNumber)
+        );
+        assert_eq!(
+            "m/0/2147483648'".parse::<DerivationPath>(),
+            Err(Error::ChildNumber)
+        );
+    }
+
+    #[test]
+    fn bip44_components() {
+        let path: DerivationPath = "m/44'/60'/1'/0/5".parse().unwrap();
+        assert_eq!(path.purpose(), Some(44));
+        assert_eq!(path.coin_type(), Some(60));
+        assert_eq!(path.account_index(), Some(1));
+
+        let account: DerivationPath = "m/44'/0'/7'".parse().unwrap();
+        assert_eq!(account.coin_type(), Some(0));
+        assert_eq!(account.account_index(), Some(7));
+
+        let segwit: DerivationPath = "m/84'/0'/0'/0/0".parse().unwrap();
+        assert_eq!(segwit.purpose(), Some(84));
+        assert_eq!(segwit.coin_type(), None);
+        assert_eq!(segwit.account_index(), None);
+
+        for path in ["m", "m/44'/60'", "m/44'/60'/0", "m/44/60'/0'"] {
+            let path: DerivationPath = path.parse().unwrap();
+            assert_eq!(path.coin_type(), None);
+            assert_eq!(path.account_index(), None);
+        }
+        assert_eq!(
+            "m/44/60'/0'".parse::<DerivationPath>().unwrap().purpose(),
+            None
+        );
+    }
+
+    #[test]
+    fn hardened_depth() {
+        let hardened_depth = |path: &str| path.parse::<DerivationPath>().unwrap().hardened_depth();
+
+        assert_eq!(hardened_depth("m"), 0);
+        assert_eq!(hardened_depth("m/44'/60'/0'/0/0"), 3);
+        assert_eq!(hardened_depth("m/0'/1'/2'"), 3);
+        assert_eq!(hardened_depth("m/0/1'/2'"), 0);
+        assert_eq!(hardened_depth("m/0'/1/2'"), 1);
+    }
+
     #[test]
     fn parent() {
         let path_m_0_2147483647h = "m/0/2147483647'".parse::<DerivationPath>().unwrap();
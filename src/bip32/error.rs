@@ -20,6 +20,9 @@ pub enum Error {
     /// Child number-related errors.
     ChildNumber,
 
+    /// Unsupported coin type.
+    CoinType,
+
     /// Cryptographic errors.
     Crypto,
 
@@ -39,6 +42,7 @@ impl Display for Error {
             Error::Base58 => f.write_str("base58 error"),
             Error::Bip39 => f.write_str("bip39 error"),
             Error::ChildNumber => f.write_str("invalid child number"),
+            Error::CoinType => f.write_str("unsupported coin type"),
             Error::Crypto => f.write_str("cryptographic error"),
             Error::Decode => f.write_str("decoding error"),
             Error::Depth => f.write_str("maximum derivation depth exceeded"),
@@ -61,6 +65,12 @@ impl From<bs58::encode::Error> for Error {
     }
 }
 
+impl From<minicbor::decode::Error> for Error {
+    fn from(_: minicbor::decode::Error) -> Error {
+        Error::Decode
+    }
+}
+
 impl From<core::array::TryFromSliceError> for Error {
     fn from(_: core::array::TryFromSliceError) -> Error {
         Error::Decode
@@ -0,0 +1,308 @@
+//! CBOR encoding of extended keys as UR `crypto-hdkey` (BCR-2020-007), as
+//! used by airgapped hardware wallets.
+//!
+//! Master private keys use the short `{is-master, key-data, chain-code}`
+//! form. Other keys record their parent fingerprint and a `crypto-keypath`
+//! origin holding their depth and, as BCR-2020-007 consumers expect, the
+//! full derivation path from the master key as its components.
+//!
+//! The path is only known for private keys derived from a seed or a root
+//! key (see [`XPrv::path`]). Other keys are encoded with an origin holding
+//! their depth and no components, so their child number is not recorded and
+//! decodes as 0.
+
+use crate::bip32::{
+    ChildNumber, DerivationPath, Error, ExtendedKey, ExtendedKeyAttrs, KeyFingerprint, Prefix,
+    Result, XPrv, XPub,
+};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use minicbor::{data::Tag, Decoder, Encoder};
+use zeroize::Zeroizing;
+
+/// CBOR tag of `crypto-keypath`.
+const TAG_KEYPATH: u64 = 304;
+
+/// `crypto-hdkey` map key: key is a master key.
+const IS_MASTER: u64 = 1;
+
+/// `crypto-hdkey` map key: key is private.
+const IS_PRIVATE: u64 = 2;
+
+/// `crypto-hdkey` map key: 33-byte key material.
+const KEY_DATA: u64 = 3;
+
+/// `crypto-hdkey` map key: chain code.
+const CHAIN_CODE: u64 = 4;
+
+/// `crypto-hdkey` map key: `crypto-keypath` the key was derived with.
+const ORIGIN: u64 = 6;
+
+/// `crypto-hdkey` map key: fingerprint of the parent key.
+const PARENT_FINGERPRINT: u64 = 8;
+
+/// `crypto-keypath` map key: path components.
+const COMPONENTS: u64 = 1;
+
+/// `crypto-keypath` map key: depth of the key.
+const DEPTH: u64 = 3;
+
+impl XPrv {
+    /// Serialize this key as a CBOR `crypto-hdkey`.
+    pub fn to_cbor(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(encode(&self.to_extended_key(Prefix::XPRV), self.path()))
+    }
+
+    /// Parse a private key from a CBOR `crypto-hdkey`.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self> {
+        decode(bytes, Prefix::XPRV)?.try_into()
+    }
+}
+
+impl XPub {
+    /// Serialize this key as a CBOR `crypto-hdkey`.
+    pub fn to_cbor(&self) -> Vec<u8> {
+        encode(&self.to_extended_key(Prefix::XPUB), None)
+    }
+
+    /// Parse a public key from a CBOR `crypto-hdkey`.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self> {
+        decode(bytes, Prefix::XPUB)?.try_into()
+    }
+}
+
+/// Encode an extended key as a `crypto-hdkey`, with its derivation path if
+/// known.
+fn encode(key: &ExtendedKey, path: Option<&DerivationPath>) -> Vec<u8> {
+    let components = path
+        .map(AsRef::as_ref)
+        .filter(|components: &&[ChildNumber]| components.len() == key.attrs.depth as usize)
+        .unwrap_or_default();
+
+    // Reserve enough up front that private key material is never reallocated
+    let mut encoder = Encoder::new(Vec::with_capacity(128 + 6 * components.len()));
+    write_hdkey(&mut encoder, key, components).expect("writing to a Vec is infallible");
+    encoder.into_writer()
+}
+
+/// Write an extended key as a `crypto-hdkey` map.
+fn write_hdkey(
+    encoder: &mut Encoder<Vec<u8>>,
+    key: &ExtendedKey,
+    components: &[ChildNumber],
+) -> core::result::Result<(), minicbor::encode::Error<Infallible>> {
+    let private = key.prefix.is_private();
+    let attrs = &key.attrs;
+
+    if private && attrs.depth == 0 {
+        encoder.map(3)?.u64(IS_MASTER)?.bool(true)?;
+    } else {
+        encoder.map(3 + private as u64 + (attrs.depth > 0) as u64)?;
+
+        if private {
+            encoder.u64(IS_PRIVATE)?.bool(true)?;
+        }
+    }
+
+    encoder.u64(KEY_DATA)?.bytes(&key.key_bytes)?;
+    encoder
+        .u64(CHAIN_CODE)?
+        .bytes(attrs.chain_code.as_bytes())?;
+
+    if private && attrs.depth == 0 {
+        return Ok(());
+    }
+
+    encoder.u64(ORIGIN)?.tag(Tag::new(TAG_KEYPATH))?.map(2)?;
+    encoder
+        .u64(COMPONENTS)?
+        .array(2 * components.len() as u64)?;
+    for child_number in components {
+        encoder
+            .u32(child_number.index())?
+            .bool(child_number.is_hardened())?;
+    }
+    encoder.u64(DEPTH)?.u8(attrs.depth)?;
+
+    if attrs.depth > 0 {
+        encoder
+            .u64(PARENT_FINGERPRINT)?
+            .u32(u32::from_be_bytes(*attrs.parent_fingerprint.as_bytes()))?;
+    }
+
+    Ok(())
+}
+
+/// Decode a `crypto-hdkey` into an extended key with the given prefix,
+/// checking that the key is private iff the prefix is.
+fn decode(bytes: &[u8], prefix: Prefix) -> Result<ExtendedKey> {
+    let mut decoder = Decoder::new(bytes);
+    let mut master = false;
+    let mut private = false;
+    let mut key_data = None;
+    let mut chain_code = None;
+    let mut origin = (None, None);
+    let mut parent_fingerprint = KeyFingerprint::default();
+
+    for _ in 0..definite(decoder.map()?)? {
+        match decoder.u64()? {
+            IS_MASTER => master = decoder.bool()?,
+            IS_PRIVATE => private = decoder.bool()?,
+            KEY_DATA => key_data = Some(decoder.bytes()?),
+            CHAIN_CODE => chain_code = Some(decoder.bytes()?),
+            ORIGIN => origin = keypath(&mut decoder)?,
+            PARENT_FINGERPRINT => parent_fingerprint = decoder.u32()?.to_be_bytes().into(),
+            _ => decoder.skip()?,
+        }
+    }
+
+    if decoder.position() != bytes.len() || (master || private) != prefix.is_private() {
+        return Err(Error::Decode);
+    }
+
+    let (depth, child_number) = match (master, origin) {
+        (true, _) | (false, (Some(0), _)) => (0, ChildNumber(0)),
+        (false, (Some(depth), child_number)) => (depth, child_number.unwrap_or_default()),
+        _ => return Err(Error::Decode),
+    };
+
+    Ok(ExtendedKey {
+        prefix,
+        attrs: ExtendedKeyAttrs {
+            depth,
+            parent_fingerprint,
+            child_number,
+            chain_code: chain_code.ok_or(Error::Decode)?.try_into()?,
+        },
+        key_bytes: key_data.ok_or(Error::Decode)?.try_into()?,
+    })
+}
+
+/// Read a tagged `crypto-keypath`, returning its depth and, if it holds the
+/// full path, the last path component.
+fn keypath(decoder: &mut Decoder<'_>) -> Result<(Option<u8>, Option<ChildNumber>)> {
+    if decoder.tag()?.as_u64() != TAG_KEYPATH {
+        return Err(Error::Decode);
+    }
+
+    let mut depth = None;
+    let mut components = 0;
+    let mut last = None;
+
+    for _ in 0..definite(decoder.map()?)? {
+        match decoder.u64()? {
+            COMPONENTS => {
+                let len = definite(decoder.array()?)?;
+                if len % 2 != 0 {
+                    return Err(Error::Decode);
+                }
+                components = len / 2;
+
+                for _ in 0..components {
+                    let index = decoder.u32()?;
+                    last = Some(ChildNumber::new(index, decoder.bool()?)?);
+                }
+            }
+            DEPTH => depth = Some(decoder.u8()?),
+            _ => decoder.skip()?,
+        }
+    }
+
+    let depth = match depth {
+        Some(depth) => depth,
+        None => u8::try_from(components).map_err(|_| Error::Decode)?,
+    };
+
+    // Partial paths do not end with this key's child number
+    let last = last.filter(|_| components == depth as u64);
+
+    Ok((Some(depth), last))
+}
+
+/// Length of a definite-length array or map. Indefinite lengths are not used
+/// by `crypto-hdkey`.
+fn definite(len: Option<u64>) -> Result<u64> {
+    len.ok_or(Error::Decode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode;
+    use crate::bip32::{ChildNumber, DerivationPath, Error, Prefix, XPrv, XPub};
+    use hex_literal::hex;
+
+    const SEED: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+
+    #[test]
+    fn master_key_vector() {
+        // BCR-2020-007 master key example (BIP32 test vector 1, chain m)
+        let cbor = hex!(
+            "a301f503582100e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35
+             045820873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508"
+        );
+        let xprv = XPrv::new(SEED).unwrap();
+
+        assert_eq!(&xprv.to_cbor()[..], &cbor[..]);
+        assert_eq!(XPrv::from_cbor(&cbor).unwrap(), xprv);
+        assert_eq!(XPub::from_cbor(&cbor), Err(Error::Decode));
+    }
+
+    #[test]
+    fn round_trip() {
+        let xprv = XPrv::new_from_path(SEED, &"m/0'/1".parse().unwrap()).unwrap();
+        let xpub = xprv.public_key();
+
+        assert_eq!(XPrv::from_cbor(&xprv.to_cbor()).unwrap(), xprv);
+
+        // The path of an extended public key is unknown, so its child
+        // number is lost
+        let decoded = XPub::from_cbor(&xpub.to_cbor()).unwrap();
+        assert_eq!(decoded.to_bytes(), xpub.to_bytes());
+        assert_eq!(decoded.attrs().chain_code, xpub.attrs().chain_code);
+        assert_eq!(decoded.attrs().depth, 2);
+        assert_eq!(
+            decoded.attrs().parent_fingerprint,
+            xpub.attrs().parent_fingerprint
+        );
+        assert_eq!(decoded.attrs().child_number, ChildNumber(0));
+
+        let master = XPrv::new(SEED).unwrap().public_key();
+        assert_eq!(XPub::from_cbor(&master.to_cbor()).unwrap(), master);
+
+        let cbor = xpub.to_cbor();
+        assert_eq!(XPub::from_cbor(&cbor[..cbor.len() - 1]), Err(Error::Decode));
+    }
+
+    #[test]
+    fn full_path_origin() {
+        let path: DerivationPath = "m/84'/0'/5".parse().unwrap();
+        let xprv = XPrv::new_from_path(SEED, &path).unwrap();
+        let cbor = xprv.to_cbor();
+
+        // origin: 304({1: [84, true, 0, true, 5, false], 3: 3})
+        let origin = hex!("06d90130a201861854f500f505f40303");
+        assert!(cbor.windows(origin.len()).any(|window| window == origin));
+        assert_eq!(XPrv::from_cbor(&cbor).unwrap(), xprv);
+
+        // Partial paths do not give the child number
+        let partial = encode(
+            &xprv.to_extended_key(Prefix::XPRV),
+            Some(&"m/5".parse().unwrap()),
+        );
+        let decoded = XPrv::from_cbor(&partial).unwrap();
+        assert_eq!(decoded.attrs().depth, 3);
+        assert_eq!(decoded.attrs().child_number, ChildNumber(0));
+    }
+
+    #[test]
+    fn hostile_input() {
+        // Unknown map entry holding a million nested tags
+        let mut nested = hex!("a11863").to_vec();
+        nested.resize(nested.len() + 1_000_000, 0xc6);
+        assert_eq!(XPub::from_cbor(&nested), Err(Error::Decode));
+
+        // Unknown map entry holding a map of 2^63 + 1 entries
+        let huge = hex!("a11863bb8000000000000001");
+        assert_eq!(XPub::from_cbor(&huge), Err(Error::Decode));
+    }
+}
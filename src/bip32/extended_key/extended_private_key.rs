@@ -1,8 +1,10 @@
 //! Extended private keys
 
 use crate::bip32::{
-    ChildNumber, Depth, Error, ExtendedKey, ExtendedKeyAttrs, ExtendedPublicKey, HmacSha512,
-    KeyFingerprint, Prefix, PrivateKey, PrivateKeyBytes, PublicKey, Result, KEY_SIZE,
+    extended_key::{slip132_script_type, slip132_version},
+    ChainCode, ChildNumber, Depth, Error, ExtendedKey, ExtendedKeyAttrs, ExtendedPublicKey,
+    HmacSha512, KeyFingerprint, NetworkKind, Prefix, PrivateKey, PrivateKeyBytes, PublicKey,
+    PublicKeyBytes, Result, ScriptType, Version, KEY_SIZE, MASTER_KEY_HMAC_KEY,
 };
 use core::{
     fmt::{self, Debug},
@@ -10,6 +12,7 @@ use core::{
 };
 //use hmac::{Mac, NewMac};
 use hmac::Mac;
+use rand::RngCore;
 use subtle::{Choice, ConstantTimeEq};
 use zeroize::Zeroize;
 
@@ -19,9 +22,12 @@ use {
     zeroize::Zeroizing,
 };
 
-/// Derivation domain separator for BIP39 keys.
-const BIP39_DOMAIN_SEPARATOR: [u8; 12] = [
-    0x42, 0x69, 0x74, 0x63, 0x6f, 0x69, 0x6e, 0x20, 0x73, 0x65, 0x65, 0x64,
+/// Valid scalar written over a private key when it is zeroized.
+///
+/// A zero scalar is not a valid private key, so the smallest valid one is used
+/// instead. The overwritten key is dropped right away and never used.
+const ZEROIZED_PLACEHOLDER: PrivateKeyBytes = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
 ];
 
 /// Extended private secp256k1 ECDSA signing key.
@@ -31,13 +37,29 @@ pub type XPrv = ExtendedPrivateKey<libsecp256k1::SecretKey>;
 /// Generic around a [`PrivateKey`] type. When the `secp256k1` feature of this
 /// crate is enabled, the [`XPrv`] type provides a convenient alias for
 /// extended ECDSA/secp256k1 private keys.
-#[derive(Clone)]
 pub struct ExtendedPrivateKey<K: PrivateKey> {
-    /// Derived private key
-    private_key: K,
+    /// Derived private key, or only its public key once blinded.
+    key: KeyMaterial<K>,
 
     /// Extended key attributes.
     attrs: ExtendedKeyAttrs,
+
+    /// [`Version`] bytes this key was created or parsed with.
+    version: Version,
+
+    /// Derivation path from the root key, if known.
+    path: Option<DerivationPath>,
+}
+
+/// Key material held by an [`ExtendedPrivateKey`].
+#[derive(Clone)]
+enum KeyMaterial<K: PrivateKey> {
+    /// The private key.
+    Private(K),
+
+    /// Public key retained when the private key has been removed with
+    /// [`ExtendedPrivateKey::blind`].
+    Blinded(PublicKeyBytes),
 }
 
 impl<K> ExtendedPrivateKey<K>
@@ -63,12 +85,23 @@ where
     where
         S: AsRef<[u8]>,
     {
-        if ![16, 32, 64, 128].contains(&seed.as_ref().len()) {
+        Self::new_with_hmac_key(seed.as_ref(), MASTER_KEY_HMAC_KEY)
+    }
+
+    /// Create the root extended key for the given seed value, using
+    /// `hmac_key` instead of [`MASTER_KEY_HMAC_KEY`] as the HMAC-SHA512 key,
+    /// e.g. `b"Nist256p1 seed"` or `b"ed25519 seed"` for SLIP-0010.
+    ///
+    /// Only the master key computation changes: the key is still used and
+    /// derived as a key of type `K`. Unlike SLIP-0010, an invalid master key
+    /// returns an error rather than being retried.
+    pub fn new_with_hmac_key(seed: &[u8], hmac_key: &[u8]) -> Result<Self> {
+        if ![16, 32, 64, 128].contains(&seed.len()) {
             return Err(Error::SeedLength);
         }
 
-        let mut hmac = HmacSha512::new_from_slice(&BIP39_DOMAIN_SEPARATOR)?;
-        hmac.update(seed.as_ref());
+        let mut hmac = HmacSha512::new_from_slice(hmac_key)?;
+        hmac.update(seed);
 
         let result = hmac.finalize().into_bytes();
         let (secret_key, chain_code) = result.split_at(KEY_SIZE);
@@ -80,7 +113,12 @@ where
             chain_code: chain_code.try_into()?,
         };
 
-        Ok(ExtendedPrivateKey { private_key, attrs })
+        Ok(ExtendedPrivateKey {
+            key: KeyMaterial::Private(private_key),
+            attrs,
+            version: Prefix::XPRV.version(),
+            path: Some(DerivationPath::default()),
+        })
     }
 
     pub fn derive_from_path(self, path: &DerivationPath) -> Result<Self> {
@@ -90,17 +128,39 @@ where
     }
 
     /// Derive a child key for a particular [`ChildNumber`].
+    ///
+    /// Children of a blinded key are blinded too, and hardened derivation
+    /// from a blinded key fails with [`Error::Crypto`].
     pub fn derive_child(&self, child_number: ChildNumber) -> Result<Self> {
+        let private_key = match &self.key {
+            KeyMaterial::Private(private_key) => private_key,
+            KeyMaterial::Blinded(_) if child_number.is_hardened() => return Err(Error::Crypto),
+            KeyMaterial::Blinded(public_key) => {
+                let xpub = ExtendedPublicKey::<K::PublicKey>::from_parts(
+                    PublicKey::from_bytes(*public_key)?,
+                    self.attrs.clone(),
+                )
+                .derive_child(child_number)?;
+
+                return Ok(ExtendedPrivateKey {
+                    key: KeyMaterial::Blinded(xpub.to_bytes()),
+                    attrs: xpub.attrs().clone(),
+                    version: self.version,
+                    path: self.child_path(child_number),
+                });
+            }
+        };
+
         let depth = self.attrs.depth.checked_add(1).ok_or(Error::Depth)?;
 
-        let mut hmac =
-            HmacSha512::new_from_slice(&self.attrs.chain_code).map_err(|_| Error::Crypto)?;
+        let mut hmac = HmacSha512::new_from_slice(self.attrs.chain_code.as_bytes())
+            .map_err(|_| Error::Crypto)?;
 
         if child_number.is_hardened() {
             hmac.update(&[0]);
-            hmac.update(&self.private_key.to_bytes());
+            hmac.update(&private_key.to_bytes());
         } else {
-            hmac.update(&self.private_key.public_key().to_bytes());
+            hmac.update(&private_key.public_key().to_bytes());
         }
 
         hmac.update(&child_number.to_bytes());
@@ -117,21 +177,54 @@ where
         //
         // ...so instead, we simply return an error if this were ever to happen,
         // as the chances of it happening are vanishingly small.
-        let private_key = self.private_key.derive_child(child_key.try_into()?)?;
+        let child_private_key = private_key.derive_child(child_key.try_into()?)?;
 
         let attrs = ExtendedKeyAttrs {
-            parent_fingerprint: self.private_key.public_key().fingerprint(),
+            parent_fingerprint: private_key.public_key().fingerprint(),
             child_number,
             chain_code: chain_code.try_into()?,
             depth,
         };
 
-        Ok(ExtendedPrivateKey { private_key, attrs })
+        Ok(ExtendedPrivateKey {
+            key: KeyMaterial::Private(child_private_key),
+            attrs,
+            version: self.version,
+            path: self.child_path(child_number),
+        })
+    }
+
+    /// Derive a child key at a random non-hardened index, for protocols
+    /// where the index of a rotated key must not be predictable by an
+    /// observer.
+    ///
+    /// The index is not returned; it can be read from the child's
+    /// [`ExtendedKeyAttrs::child_number`].
+    pub fn random_child(&self, rng: &mut impl RngCore) -> Result<Self> {
+        // HARDENED_FLAG is a power of two, so the reduction is unbiased
+        self.derive_child(ChildNumber(rng.next_u32() % ChildNumber::HARDENED_FLAG))
     }
 
     /// Borrow the derived private key value.
-    pub fn private_key(&self) -> &K {
-        &self.private_key
+    ///
+    /// Returns [`Error::Crypto`] if the key has been blinded.
+    pub fn private_key(&self) -> Result<&K> {
+        match &self.key {
+            KeyMaterial::Private(private_key) => Ok(private_key),
+            KeyMaterial::Blinded(_) => Err(Error::Crypto),
+        }
+    }
+
+    /// Mutably borrow the derived private key value.
+    ///
+    /// Mutating the key invalidates the extended key: its chain code,
+    /// fingerprints and any derived children no longer correspond to it.
+    /// Returns [`Error::Crypto`] if the key has been blinded.
+    pub fn private_key_mut(&mut self) -> Result<&mut K> {
+        match &mut self.key {
+            KeyMaterial::Private(private_key) => Ok(private_key),
+            KeyMaterial::Blinded(_) => Err(Error::Crypto),
+        }
     }
 
     /// Serialize the derived public key as bytes.
@@ -139,15 +232,146 @@ where
         self.into()
     }
 
+    /// Consume this key, zeroizing the private key, and return the
+    /// corresponding [`ExtendedPublicKey`].
+    ///
+    /// This is the recommended way to hand key material to untrusted code
+    /// which only needs to derive addresses: the conversion is one-way and
+    /// the private key does not outlive the call.
+    pub fn into_watch_only(mut self) -> ExtendedPublicKey<K::PublicKey> {
+        let xpub = self.public_key();
+        self.zeroize();
+        xpub
+    }
+
+    /// Get the non-extended public key which corresponds to this key,
+    /// including when it has been blinded.
+    pub(crate) fn raw_public_key(&self) -> K::PublicKey {
+        match &self.key {
+            KeyMaterial::Private(private_key) => private_key.public_key(),
+            KeyMaterial::Blinded(bytes) => {
+                PublicKey::from_bytes(*bytes).expect("blinded public key is valid")
+            }
+        }
+    }
+
+    /// Return a copy of this key with the private key removed, for use by
+    /// parties which must only ever see public key material (e.g. in
+    /// multi-party computation).
+    ///
+    /// The public key, chain code and other attributes are preserved, so
+    /// [`Self::public_key`] and non-hardened derivation keep working. The
+    /// private key serializes as all zeros, and operations which need it
+    /// ([`Self::private_key`], signing, hardened derivation) return
+    /// [`Error::Crypto`].
+    pub fn blind(&self) -> Self {
+        ExtendedPrivateKey {
+            key: KeyMaterial::Blinded(self.raw_public_key().to_bytes()),
+            attrs: self.attrs.clone(),
+            version: self.version,
+            path: self.path.clone(),
+        }
+    }
+
+    /// Has the private key been removed with [`Self::blind`]?
+    pub fn is_blinded(&self) -> bool {
+        matches!(self.key, KeyMaterial::Blinded(_))
+    }
+
+    /// Public key retained by a blinded key, or all zeros if the private key
+    /// is present.
+    fn blinded_public_key(&self) -> PublicKeyBytes {
+        match &self.key {
+            KeyMaterial::Private(_) => [0u8; KEY_SIZE + 1],
+            KeyMaterial::Blinded(public_key) => *public_key,
+        }
+    }
+
     /// Get attributes for this key such as depth, parent fingerprint,
     /// child number, and chain code.
     pub fn attrs(&self) -> &ExtendedKeyAttrs {
         &self.attrs
     }
 
+    /// Get the chain code of this key or, if it is all zeros, a chain code
+    /// derived deterministically from the private key as
+    /// `HMAC-SHA512(key="chain code", data=private_key)[..32]`.
+    ///
+    /// The fallback is only appropriate for keys imported without a chain
+    /// code (e.g. from WIF or a raw 32-byte secret) which must be used with
+    /// APIs expecting an extended key. It is not part of BIP32: other
+    /// wallets will not derive the same children from such a key.
+    pub fn chain_code_or_derived(&self) -> ChainCode {
+        if self.attrs.chain_code != ChainCode::default() {
+            return self.attrs.chain_code;
+        }
+
+        let mut hmac =
+            HmacSha512::new_from_slice(b"chain code").expect("HMAC accepts keys of any length");
+        let mut private_key = self.to_bytes();
+        hmac.update(&private_key);
+        private_key.zeroize();
+
+        let result = hmac.finalize().into_bytes();
+        ChainCode::try_from(&result[..KEY_SIZE]).expect("HMAC-SHA512 output is 64 bytes")
+    }
+
+    /// Get the [`DerivationPath`] of this key from its root key.
+    ///
+    /// This is known for keys derived from a seed or a root key, and `None`
+    /// for keys parsed from a serialized non-root extended key.
+    pub fn path(&self) -> Option<&DerivationPath> {
+        self.path.as_ref()
+    }
+
+    /// Path of the child with the given [`ChildNumber`], if this key's path
+    /// is known.
+    fn child_path(&self, child_number: ChildNumber) -> Option<DerivationPath> {
+        self.path.clone().map(|mut path| {
+            path.push(child_number);
+            path
+        })
+    }
+
+    /// Check this key against a signing policy: is its [`Self::path`] equal
+    /// to, or below, one of the `allowed` paths?
+    ///
+    /// Returns `false` for keys whose path is unknown.
+    pub fn path_is_allowed(&self, allowed: &[DerivationPath]) -> bool {
+        match &self.path {
+            Some(path) => allowed
+                .iter()
+                .any(|prefix| path.as_ref().starts_with(prefix.as_ref())),
+            None => false,
+        }
+    }
+
+    /// Get the [`Version`] bytes of this key.
+    ///
+    /// Keys created from a seed use [`Prefix::XPRV`], parsed keys keep the
+    /// version they were serialized with, and children inherit the version
+    /// of their parent.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Return this key with its [`Version`] bytes replaced, e.g. to display a
+    /// mainnet key in testnet format.
+    ///
+    /// Only the version changes: the key is not re-derived, and all other
+    /// attributes are kept as they are.
+    pub fn with_version(self, version: Version) -> Self {
+        ExtendedPrivateKey { version, ..self }
+    }
+
     /// Serialize the raw private key as a byte array.
+    ///
+    /// Returns all zeros for a blinded key.
     pub fn to_bytes(&self) -> PrivateKeyBytes {
-        self.private_key.to_bytes()
+        match &self.key {
+            KeyMaterial::Private(private_key) => private_key.to_bytes(),
+            KeyMaterial::Blinded(_) => [0u8; KEY_SIZE],
+        }
     }
 
     /// Serialize this key as an [`ExtendedKey`].
@@ -169,6 +393,344 @@ where
     pub fn to_string(&self, prefix: Prefix) -> Zeroizing<String> {
         Zeroizing::new(self.to_extended_key(prefix).to_string())
     }
+
+    /// Export this key and all of its attributes as human-readable JSON,
+    /// for development and for setting up airgapped signing devices:
+    ///
+    /// ```text
+    /// {"warning":"...","version":"0488ade4","depth":1,"parent_fingerprint":"3442193e",
+    ///  "child_number":2147483648,"chain_code":"...","private_key":"...","path":"m/0'"}
+    /// ```
+    ///
+    /// `path` is `null` if the key's derivation path is unknown.
+    ///
+    /// **Warning:** the private key is stored in the clear, as the
+    /// `"warning"` field of the output states. Returns [`Error::Crypto`] for
+    /// blinded keys.
+    #[cfg(feature = "development")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "development")))]
+    pub fn to_unencrypted_json(&self) -> Result<Zeroizing<String>> {
+        if self.is_blinded() {
+            return Err(Error::Crypto);
+        }
+
+        let mut json = serde_json::json!({
+            "warning": UNENCRYPTED_JSON_WARNING,
+            "version": format!("{:08x}", self.version),
+            "depth": self.attrs.depth,
+            "parent_fingerprint": format!("{:x}", self.attrs.parent_fingerprint),
+            "child_number": u32::from(self.attrs.child_number),
+            "chain_code": format!("{:x}", self.attrs.chain_code),
+            "private_key": hex::encode(self.to_bytes()),
+            "path": self.path.as_ref().map(ToString::to_string),
+        });
+        let serialized = Zeroizing::new(json.to_string());
+
+        zeroize_json_private_key(&mut json);
+        Ok(serialized)
+    }
+
+    /// Import a key exported with [`ExtendedPrivateKey::to_unencrypted_json`].
+    ///
+    /// Returns [`Error::Decode`] if the JSON is malformed or a field is
+    /// missing or invalid, including a `path` which does not match `depth`.
+    #[cfg(feature = "development")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "development")))]
+    pub fn from_unencrypted_json(json: &str) -> Result<Self> {
+        let mut json: serde_json::Value = serde_json::from_str(json).map_err(|_| Error::Decode)?;
+
+        let key = Self::from_json_value(&json);
+        zeroize_json_private_key(&mut json);
+        key
+    }
+
+    /// Import a key from the parsed JSON of
+    /// [`ExtendedPrivateKey::to_unencrypted_json`].
+    #[cfg(feature = "development")]
+    fn from_json_value(json: &serde_json::Value) -> Result<Self> {
+        let string = |name: &str| json.get(name).and_then(|v| v.as_str()).ok_or(Error::Decode);
+        let number = |name: &str| json.get(name).and_then(|v| v.as_u64()).ok_or(Error::Decode);
+        let hex = |name: &str| hex::decode(string(name)?).map_err(|_| Error::Decode);
+
+        let version = Version::from_str_radix(string("version")?, 16).map_err(|_| Error::Decode)?;
+        let private_key = Zeroizing::new(hex("private_key")?);
+        if private_key.len() != KEY_SIZE {
+            return Err(Error::Decode);
+        }
+
+        let mut key_bytes = [0u8; KEY_SIZE + 1];
+        key_bytes[1..].copy_from_slice(&private_key);
+
+        let extended_key = ExtendedKey {
+            prefix: Prefix::try_from(version)?,
+            attrs: ExtendedKeyAttrs {
+                depth: number("depth")?.try_into().map_err(|_| Error::Decode)?,
+                parent_fingerprint: hex("parent_fingerprint")?.as_slice().try_into()?,
+                child_number: ChildNumber(
+                    number("child_number")?
+                        .try_into()
+                        .map_err(|_| Error::Decode)?,
+                ),
+                chain_code: hex("chain_code")?.as_slice().try_into()?,
+            },
+            key_bytes,
+        };
+        key_bytes.zeroize();
+        let mut key = Self::try_from(extended_key)?;
+
+        match json.get("path") {
+            Some(serde_json::Value::Null) => {}
+            Some(serde_json::Value::String(path)) => {
+                let path: DerivationPath = path.parse()?;
+
+                if path.len() != key.attrs.depth as usize {
+                    return Err(Error::Decode);
+                }
+
+                key.path = Some(path);
+            }
+            _ => return Err(Error::Decode),
+        }
+
+        Ok(key)
+    }
+}
+
+impl XPrv {
+    /// Create the root key from a 32-byte master secret, as done by
+    /// protocols which derive master keys directly from a shared or
+    /// generated secret rather than from a BIP39 seed.
+    ///
+    /// This is the same computation as [`XPrv::new`], which accepts seeds of
+    /// several lengths: the name only makes the intent explicit.
+    pub fn from_master_secret(secret: &[u8; KEY_SIZE]) -> Result<XPrv> {
+        XPrv::new(secret)
+    }
+
+    /// Compute a non-secret CRC-32 hint of the passphrase used to generate
+    /// this key, which wallets can store alongside the extended key to help
+    /// users remember which passphrase they used.
+    ///
+    /// **Warning:** the hint is not a secret and makes brute-forcing the
+    /// passphrase cheaper: it leaks information about the passphrase,
+    /// including its length for short passphrases. Never store it next to a
+    /// key protected only by the passphrase.
+    pub fn passphrase_checksum(passphrase: &str) -> u32 {
+        crate::crypto::crc32(passphrase.as_bytes())
+    }
+
+    /// Encode the 32 private key bytes of this key as a 24-word BIP39
+    /// phrase, for backup schemes that treat the private key as entropy.
+    ///
+    /// **Warning:** this is the private key as a mnemonic, NOT the wallet's
+    /// original seed phrase: generating a seed from the returned phrase gives
+    /// an unrelated wallet. Only the private key can be recovered from it,
+    /// with [`Mnemonic::entropy`][`crate::bip39::Mnemonic::entropy`], and the
+    /// chain code is not included.
+    ///
+    /// Returns [`Error::Crypto`] if the key has been blinded.
+    pub fn to_mnemonic(&self, lang: crate::bip39::Language) -> Result<crate::bip39::Mnemonic> {
+        let mut entropy = self.private_key()?.to_bytes();
+        let mnemonic = crate::bip39::Mnemonic::from_entropy(&entropy, lang)
+            .expect("32 bytes is valid BIP39 entropy");
+        entropy.zeroize();
+
+        Ok(mnemonic)
+    }
+
+    /// Serialize this key with the SLIP-132 prefix for its SegWit script
+    /// type: `yprv` for P2SH-wrapped P2WPKH (BIP49) and `zprv` for native
+    /// P2WPKH (BIP84), or `uprv`/`vprv` if this key has a testnet version.
+    /// The multi-signature P2WSH script types use `Yprv`/`Zprv` and
+    /// `Uprv`/`Vprv` instead.
+    ///
+    /// If `script_type` is `None`, it is inferred from the purpose of
+    /// [`Self::path`], which must be known and be `49'` or `84'`. Returns
+    /// [`Error::Decode`] if the script type is unknown or not SegWit.
+    pub fn to_segwit_string(&self, script_type: Option<ScriptType>) -> Result<Zeroizing<String>> {
+        let script_type = match script_type {
+            Some(script_type) => script_type,
+            None => match self
+                .path
+                .as_ref()
+                .and_then(|path| path.iter().next())
+                .filter(|purpose| purpose.is_hardened())
+                .map(|purpose| purpose.index())
+            {
+                Some(49) => ScriptType::P2SH_P2WPKH,
+                Some(84) => ScriptType::P2WPKH,
+                _ => return Err(Error::Decode),
+            },
+        };
+
+        let network =
+            slip132_script_type(self.version).map_or(NetworkKind::Main, |(_, network)| network);
+
+        Ok(self.to_string(segwit_prefix(script_type, network, true)?))
+    }
+
+    /// Derive the BIP44 account key `m/44'/coin'/account'` from this master
+    /// key and export its [`XPub`][`crate::bip32::XPub`] as JSON, for
+    /// initializing a watch-only wallet from an airgapped signing device:
+    ///
+    /// ```text
+    /// {"xpub":"xpub...","path":"m/44'/0'/0'","created_at":1700000000}
+    /// ```
+    ///
+    /// `created_at` is the wallet creation time in seconds since the Unix
+    /// epoch, e.g. the current time from the caller's clock: it is written
+    /// as-is, so that the output is deterministic. Returns [`Error::Depth`]
+    /// if this is not a master (depth 0) key.
+    pub fn export_account_public_key_json(
+        &self,
+        coin: u32,
+        account: u32,
+        created_at: u64,
+    ) -> Result<String> {
+        if self.attrs.depth != 0 {
+            return Err(Error::Depth);
+        }
+
+        let mut path = DerivationPath::default();
+        path.extend([
+            ChildNumber::new(44, true)?,
+            ChildNumber::new(coin, true)?,
+            ChildNumber::new(account, true)?,
+        ]);
+
+        let account_key = self.clone().derive_from_path(&path)?;
+
+        Ok(format!(
+            r#"{{"xpub":"{}","path":"{}","created_at":{}}}"#,
+            account_key.public_key().to_string(Prefix::XPUB),
+            path,
+            created_at
+        ))
+    }
+
+    /// Sign a legacy Ethereum transaction with EIP-155 replay protection.
+    ///
+    /// `tx_rlp` must be the RLP encoding of the unsigned transaction, i.e.
+    /// the list `[nonce, gasPrice, gasLimit, to, value, data]` without the
+    /// trailing `v`, `r`, `s` fields. The signed payload is that list
+    /// extended with `[chainId, 0, 0]`, hashed with Keccak-256.
+    ///
+    /// The signature is returned as `r || s || recovery_id`. The transaction
+    /// `v` value is `recovery_id + chain_id * 2 + 35`, which does not fit in
+    /// a byte for most chain IDs and so must be computed by the caller.
+    ///
+    /// Returns [`Error::Decode`] if `tx_rlp` is not a single RLP list, or
+    /// [`Error::Crypto`] if the key has been blinded.
+    pub fn sign_ethereum_transaction(&self, tx_rlp: &[u8], chain_id: u64) -> Result<[u8; 65]> {
+        if self.is_blinded() {
+            return Err(Error::Crypto);
+        }
+
+        let fields = rlp_list_payload(tx_rlp)?;
+
+        let mut payload = fields.to_vec();
+        let chain_id = chain_id.to_be_bytes();
+        let chain_id = &chain_id[chain_id.iter().take_while(|b| **b == 0).count()..];
+        match chain_id {
+            [byte] if *byte < 0x80 => payload.push(*byte),
+            _ => {
+                payload.push(0x80 + chain_id.len() as u8);
+                payload.extend_from_slice(chain_id);
+            }
+        }
+        payload.extend_from_slice(&[0x80, 0x80]);
+
+        let mut unsigned = rlp_list_header(payload.len());
+        unsigned.extend_from_slice(&payload);
+
+        self.sign_digest(&crate::crypto::keccak256(&unsigned))
+    }
+
+    /// Sign a batch of 32-byte message digests with this key.
+    ///
+    /// Each signature is returned in the 65-byte `r || s || v` layout used by
+    /// Ethereum, where `v` is the recovery id. Returns [`Error::Crypto`] if
+    /// the key has been blinded.
+    pub fn sign_batch(&self, messages: &[[u8; 32]]) -> Result<Vec<[u8; 65]>> {
+        if self.is_blinded() {
+            return Err(Error::Crypto);
+        }
+
+        messages
+            .iter()
+            .map(|message| self.sign_digest(message))
+            .collect()
+    }
+
+    /// Derive the key at `path` from `root`, sign a 32-byte message digest
+    /// with it and zeroize it again, so the derived key only exists for the
+    /// duration of the call.
+    ///
+    /// The signature uses the same `r || s || v` layout as
+    /// [`Self::sign_batch`]. Returns [`Error::Crypto`] if `root` has been
+    /// blinded.
+    pub fn sign_with_path(root: &XPrv, path: &DerivationPath, msg: &[u8; 32]) -> Result<[u8; 65]> {
+        let mut key = root.clone();
+
+        let derived = path.iter().try_for_each(|child_number| {
+            let child = key.derive_child(child_number)?;
+            core::mem::replace(&mut key, child).zeroize();
+            Ok(())
+        });
+
+        let signature = derived.and_then(|()| key.sign_digest(msg));
+
+        key.zeroize();
+        signature
+    }
+
+    /// Sign a 32-byte message digest, returning `r || s || v`.
+    ///
+    /// Returns [`Error::Crypto`] if the key has been blinded.
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<[u8; 65]> {
+        let message = libsecp256k1::Message::parse(digest);
+        let (signature, recovery_id) = libsecp256k1::sign(&message, self.private_key()?);
+
+        let mut bytes = [0u8; 65];
+        bytes[..64].copy_from_slice(&signature.serialize());
+        bytes[64] = recovery_id.serialize();
+        Ok(bytes)
+    }
+}
+
+/// Get the payload of an RLP-encoded list which spans all of `bytes`.
+fn rlp_list_payload(bytes: &[u8]) -> Result<&[u8]> {
+    let (&prefix, rest) = bytes.split_first().ok_or(Error::Decode)?;
+
+    let (len, payload) = match prefix {
+        0xc0..=0xf7 => ((prefix - 0xc0) as usize, rest),
+        0xf8..=0xff if rest.len() >= (prefix - 0xf7) as usize => {
+            let (len, payload) = rest.split_at((prefix - 0xf7) as usize);
+            let len = len.iter().fold(0, |len, byte| len << 8 | *byte as usize);
+            (len, payload)
+        }
+        _ => return Err(Error::Decode),
+    };
+
+    if payload.len() != len {
+        return Err(Error::Decode);
+    }
+
+    Ok(payload)
+}
+
+/// Encode the RLP header of a list with a payload of `len` bytes.
+fn rlp_list_header(len: usize) -> Vec<u8> {
+    if len < 56 {
+        return [0xc0 + len as u8].to_vec();
+    }
+
+    let len = len.to_be_bytes();
+    let len = &len[len.iter().take_while(|b| **b == 0).count()..];
+
+    let mut header = [0xf7 + len.len() as u8].to_vec();
+    header.extend_from_slice(len);
+    header
 }
 
 impl<K> ConstantTimeEq for ExtendedPrivateKey<K>
@@ -177,16 +739,23 @@ where
 {
     fn ct_eq(&self, other: &Self) -> Choice {
         let mut key_a = self.to_bytes();
-        let mut key_b = self.to_bytes();
+        let mut key_b = other.to_bytes();
 
         let result = key_a.ct_eq(&key_b)
+            & self.blinded_public_key().ct_eq(&other.blinded_public_key())
             & self.attrs.depth.ct_eq(&other.attrs.depth)
             & self
                 .attrs
                 .parent_fingerprint
-                .ct_eq(&other.attrs.parent_fingerprint)
+                .as_bytes()
+                .ct_eq(other.attrs.parent_fingerprint.as_bytes())
             & self.attrs.child_number.0.ct_eq(&other.attrs.child_number.0)
-            & self.attrs.chain_code.ct_eq(&other.attrs.chain_code);
+            & self
+                .attrs
+                .chain_code
+                .as_bytes()
+                .ct_eq(other.attrs.chain_code.as_bytes())
+            & (self.is_blinded() as u8).ct_eq(&(other.is_blinded() as u8));
 
         key_a.zeroize();
         key_b.zeroize();
@@ -195,6 +764,26 @@ where
     }
 }
 
+/// Cloning creates another in-memory copy of the private key and chain code,
+/// doubling the attack surface: each copy is zeroized only when it is dropped
+/// or explicitly [`Zeroize`]d. Prefer borrowing a key over cloning it.
+///
+/// Unused clones are already flagged by the `#[must_use]` attribute of
+/// [`Clone::clone`].
+impl<K> Clone for ExtendedPrivateKey<K>
+where
+    K: PrivateKey + Clone,
+{
+    fn clone(&self) -> Self {
+        ExtendedPrivateKey {
+            key: self.key.clone(),
+            attrs: self.attrs.clone(),
+            version: self.version,
+            path: self.path.clone(),
+        }
+    }
+}
+
 impl<K> Debug for ExtendedPrivateKey<K>
 where
     K: PrivateKey,
@@ -208,6 +797,28 @@ where
     }
 }
 
+/// Overwrites the private key (with a placeholder, as a zero scalar is not a
+/// valid private key) and the chain code, leaving the key in the same state as
+/// [`ExtendedPrivateKey::blind`]: only the public key is kept, and operations
+/// which need the private key return [`Error::Crypto`].
+impl<K> Zeroize for ExtendedPrivateKey<K>
+where
+    K: PrivateKey,
+{
+    fn zeroize(&mut self) {
+        let public_key = self.raw_public_key().to_bytes();
+
+        if let KeyMaterial::Private(private_key) = &mut self.key {
+            *private_key = PrivateKey::from_bytes(&ZEROIZED_PLACEHOLDER)
+                .expect("placeholder is a valid private key");
+            core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+        }
+
+        self.key = KeyMaterial::Blinded(public_key);
+        self.attrs.chain_code.zeroize();
+    }
+}
+
 /// NOTE: uses [`ConstantTimeEq`] internally
 impl<K> Eq for ExtendedPrivateKey<K> where K: PrivateKey {}
 
@@ -241,11 +852,410 @@ where
     fn try_from(extended_key: ExtendedKey) -> Result<ExtendedPrivateKey<K>> {
         if extended_key.prefix.is_private() && extended_key.key_bytes[0] == 0 {
             Ok(ExtendedPrivateKey {
-                private_key: PrivateKey::from_bytes(extended_key.key_bytes[1..].try_into()?)?,
+                key: KeyMaterial::Private(PrivateKey::from_bytes(
+                    extended_key.key_bytes[1..].try_into()?,
+                )?),
                 attrs: extended_key.attrs.clone(),
+                version: extended_key.prefix.version(),
+                path: (extended_key.attrs.depth == 0).then(DerivationPath::default),
             })
         } else {
             Err(Error::Crypto)
         }
     }
 }
+
+/// Value of the `"warning"` field of unencrypted JSON exports.
+#[cfg(feature = "development")]
+const UNENCRYPTED_JSON_WARNING: &str =
+    "UNENCRYPTED PRIVATE KEY: anyone who can read this can spend its funds";
+
+/// Overwrite the private key in the JSON of an unencrypted export.
+#[cfg(feature = "development")]
+fn zeroize_json_private_key(json: &mut serde_json::Value) {
+    if let Some(serde_json::Value::String(private_key)) = json.get_mut("private_key") {
+        private_key.zeroize();
+    }
+}
+
+/// SLIP-132 [`Prefix`] of SegWit keys with the given script type and
+/// network.
+///
+/// Returns [`Error::Decode`] for non-SegWit script types.
+pub(crate) fn segwit_prefix(
+    script_type: ScriptType,
+    network: NetworkKind,
+    private: bool,
+) -> Result<Prefix> {
+    match script_type {
+        ScriptType::P2SH_P2WPKH
+        | ScriptType::P2WPKH
+        | ScriptType::P2SH_P2WSH
+        | ScriptType::P2WSH => slip132_version(script_type, network, private)
+            .ok_or(Error::Decode)?
+            .try_into(),
+        _ => Err(Error::Decode),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bip32::extended_key::bip84_seed;
+    use crate::bip32::{
+        ChainCode, ChildNumber, DerivationPath, Error, Prefix, ScriptType, XPrv,
+        MASTER_KEY_HMAC_KEY,
+    };
+    use crate::bip39::Language;
+    use hex_literal::hex;
+    use zeroize::Zeroize;
+
+    const SEED: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+
+    #[test]
+    fn ct_eq() {
+        let xprv = XPrv::new(SEED).unwrap();
+        let mut other = xprv.clone();
+        *other.private_key_mut().unwrap() = libsecp256k1::SecretKey::parse(&[0x46; 32]).unwrap();
+
+        assert_eq!(xprv, xprv.clone());
+        assert_ne!(xprv, other);
+        assert_ne!(xprv, xprv.blind());
+        assert_eq!(xprv.blind(), xprv.blind());
+        assert_ne!(xprv.blind(), other.blind());
+    }
+
+    #[test]
+    fn blind() {
+        let xprv = XPrv::new(SEED).unwrap();
+        let blinded = xprv.blind();
+
+        assert!(blinded.is_blinded());
+        assert!(!xprv.is_blinded());
+        assert_eq!(blinded.to_bytes(), [0u8; 32]);
+        assert_eq!(blinded.public_key(), xprv.public_key());
+        assert_ne!(blinded, xprv);
+    }
+
+    #[test]
+    fn blind_derive_child() {
+        let blinded = XPrv::new(SEED).unwrap().blind();
+
+        let hardened = ChildNumber::new(0, true).unwrap();
+        assert_eq!(blinded.derive_child(hardened), Err(Error::Crypto));
+
+        let normal = ChildNumber::new(1, false).unwrap();
+        let child = blinded.derive_child(normal).unwrap();
+        assert!(child.is_blinded());
+        assert_eq!(
+            child.public_key(),
+            blinded.public_key().derive_child(normal).unwrap()
+        );
+    }
+
+    #[test]
+    fn chain_code_or_derived() {
+        let mut xprv = XPrv::new(SEED).unwrap();
+        assert_eq!(xprv.chain_code_or_derived(), xprv.attrs().chain_code);
+
+        xprv.attrs.chain_code = ChainCode::default();
+        assert_eq!(
+            xprv.chain_code_or_derived(),
+            ChainCode::from_bytes(hex!(
+                "4632ac95f2ac47f46218022cdc9ab01f316ca231462d52c38b298f7f0a6f4b08"
+            ))
+        );
+    }
+
+    #[test]
+    fn to_segwit_string() {
+        let seed = bip84_seed();
+
+        // BIP84 test vector account key
+        let account = XPrv::new_from_path(&seed, &"m/84'/0'/0'".parse().unwrap()).unwrap();
+        let zprv = "zprvAdG4iTXWBoARxkkzNpNh8r6Qag3irQB8PzEMkAFeTRXxHpbF9z4QgEvBRmfvqWvGp42t42nvgGpNgYSJA9iefm1yYNZKEm7z6qUWCroSQnE";
+        assert_eq!(account.to_segwit_string(None).unwrap().as_str(), zprv);
+        assert!(account
+            .to_segwit_string(Some(ScriptType::P2SH_P2WPKH))
+            .unwrap()
+            .starts_with("yprv"));
+
+        let testnet = account.clone().with_version(Prefix::TPRV.version());
+        assert!(testnet.to_segwit_string(None).unwrap().starts_with("vprv"));
+
+        let legacy = XPrv::new_from_path(&seed, &"m/44'/0'/0'".parse().unwrap()).unwrap();
+        assert_eq!(legacy.to_segwit_string(None), Err(Error::Decode));
+        assert_eq!(
+            account.to_segwit_string(Some(ScriptType::P2TR)),
+            Err(Error::Decode)
+        );
+
+        let parsed: XPrv = account.to_string(Prefix::XPRV).parse().unwrap();
+        assert_eq!(parsed.to_segwit_string(None), Err(Error::Decode));
+        assert!(parsed
+            .to_segwit_string(Some(ScriptType::P2WPKH))
+            .unwrap()
+            .starts_with("zprv"));
+    }
+
+    #[test]
+    fn from_master_secret() {
+        let secret = [7u8; 32];
+
+        assert_eq!(
+            XPrv::from_master_secret(&secret).unwrap(),
+            XPrv::new(secret).unwrap()
+        );
+    }
+
+    #[test]
+    fn new_with_hmac_key() {
+        assert_eq!(
+            XPrv::new_with_hmac_key(&SEED, MASTER_KEY_HMAC_KEY).unwrap(),
+            XPrv::new(SEED).unwrap()
+        );
+
+        // SLIP-0010 test vector 1 master keys
+        let nist256p1 = XPrv::new_with_hmac_key(&SEED, b"Nist256p1 seed").unwrap();
+        assert_eq!(
+            nist256p1.to_bytes(),
+            hex!("612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2")
+        );
+        assert_eq!(
+            nist256p1.attrs().chain_code.as_bytes(),
+            &hex!("beeb672fe4621673f722f38529c07392fecaa61015c80c34f29ce8b41b3cb6ea")
+        );
+
+        let ed25519 = XPrv::new_with_hmac_key(&SEED, b"ed25519 seed").unwrap();
+        assert_eq!(
+            ed25519.to_bytes(),
+            hex!("2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7")
+        );
+        assert_eq!(
+            ed25519.attrs().chain_code.as_bytes(),
+            &hex!("90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb")
+        );
+
+        assert_eq!(
+            XPrv::new_with_hmac_key(&SEED[..15], MASTER_KEY_HMAC_KEY),
+            Err(Error::SeedLength)
+        );
+    }
+
+    #[cfg(feature = "development")]
+    #[test]
+    fn unencrypted_json() {
+        let path: DerivationPath = "m/0'/1".parse().unwrap();
+        let xprv = XPrv::new_from_path(SEED, &path).unwrap();
+        let json = xprv.to_unencrypted_json().unwrap();
+
+        assert!(json.starts_with(r#"{"warning":"UNENCRYPTED PRIVATE KEY"#));
+        assert!(json.contains(r#""version":"0488ade4","depth":2,"#));
+        assert!(json.ends_with(r#""path":"m/0'/1"}"#));
+
+        let parsed = XPrv::from_unencrypted_json(&json).unwrap();
+        assert_eq!(parsed, xprv);
+        assert_eq!(parsed.path(), Some(&path));
+
+        // Parsed keys have an unknown path
+        let parsed: XPrv = xprv.to_string(Prefix::XPRV).parse().unwrap();
+        let json = parsed.to_unencrypted_json().unwrap();
+        assert!(json.ends_with(r#""path":null}"#));
+        assert_eq!(XPrv::from_unencrypted_json(&json).unwrap().path(), None);
+
+        let bad_path = xprv
+            .to_unencrypted_json()
+            .unwrap()
+            .replace("m/0'/1", "m/0'");
+        assert_eq!(XPrv::from_unencrypted_json(&bad_path), Err(Error::Decode));
+        assert_eq!(
+            XPrv::from_unencrypted_json(&json[..json.len() - 1]),
+            Err(Error::Decode)
+        );
+
+        assert_eq!(xprv.blind().to_unencrypted_json(), Err(Error::Crypto));
+    }
+
+    #[test]
+    fn with_version() {
+        let xprv = XPrv::new(SEED).unwrap();
+        assert_eq!(xprv.version(), Prefix::XPRV.version());
+
+        let tprv = xprv.clone().with_version(Prefix::TPRV.version());
+        assert_eq!(tprv.version(), Prefix::TPRV.version());
+        assert_eq!(tprv.attrs(), xprv.attrs());
+        assert_eq!(tprv.to_bytes(), xprv.to_bytes());
+
+        let child = tprv
+            .derive_child(ChildNumber::new(0, true).unwrap())
+            .unwrap();
+        assert_eq!(child.version(), Prefix::TPRV.version());
+    }
+
+    #[test]
+    fn to_mnemonic() {
+        let xprv = XPrv::new(SEED).unwrap();
+        let mnemonic = xprv.to_mnemonic(Language::English).unwrap();
+
+        assert_eq!(mnemonic.phrase().split(' ').count(), 24);
+        assert_eq!(mnemonic.entropy(), &xprv.to_bytes()[..]);
+
+        // Not the all-zero entropy phrase of the blinded key bytes
+        assert_eq!(
+            xprv.blind().to_mnemonic(Language::English).err(),
+            Some(Error::Crypto)
+        );
+    }
+
+    #[test]
+    fn into_watch_only() {
+        let xprv = XPrv::new(SEED).unwrap();
+        let xpub = xprv.public_key();
+
+        assert_eq!(xprv.into_watch_only(), xpub);
+    }
+
+    #[test]
+    fn random_child() {
+        let xprv = XPrv::new(SEED).unwrap();
+        let child = xprv.random_child(&mut rand::thread_rng()).unwrap();
+        let child_number = child.attrs().child_number;
+
+        assert!(!child_number.is_hardened());
+        assert_eq!(child, xprv.derive_child(child_number).unwrap());
+    }
+
+    #[test]
+    fn passphrase_checksum() {
+        assert_eq!(XPrv::passphrase_checksum(""), 0);
+        assert_eq!(XPrv::passphrase_checksum("123456789"), 0xcbf4_3926);
+        assert_eq!(XPrv::passphrase_checksum("TREZOR"), 0x5b6b_1792);
+    }
+
+    #[test]
+    fn zeroize() {
+        let mut xprv = XPrv::new(SEED).unwrap();
+        xprv.zeroize();
+
+        assert_eq!(xprv.attrs().chain_code.as_bytes(), &[0u8; 32]);
+        assert_eq!(xprv.to_bytes(), [0u8; 32]);
+        assert!(xprv.is_blinded());
+        assert_eq!(xprv.private_key().err(), Some(Error::Crypto));
+        assert_eq!(xprv.sign_batch(&[[1u8; 32]]), Err(Error::Crypto));
+        assert_eq!(
+            xprv.derive_child(ChildNumber::new(0, true).unwrap()),
+            Err(Error::Crypto)
+        );
+    }
+
+    #[test]
+    fn path_is_allowed() {
+        let path: DerivationPath = "m/44'/60'/0'/0/3".parse().unwrap();
+        let xprv = XPrv::new_from_path(SEED, &path).unwrap();
+        assert_eq!(xprv.path(), Some(&path));
+
+        let allowed = ["m/44'/0'".parse().unwrap(), "m/44'/60'/0'".parse().unwrap()];
+        assert!(xprv.path_is_allowed(&allowed));
+        assert!(!xprv.path_is_allowed(&allowed[..1]));
+        assert!(!xprv.path_is_allowed(&[]));
+
+        // Path metadata is lost when serializing non-root keys
+        let parsed: XPrv = xprv.to_string(Prefix::XPRV).parse().unwrap();
+        assert_eq!(parsed.path(), None);
+        assert!(!parsed.path_is_allowed(&allowed));
+    }
+
+    #[test]
+    fn export_account_public_key_json() {
+        let xprv = XPrv::new(SEED).unwrap();
+        let json = xprv
+            .export_account_public_key_json(0, 1, 1_700_000_000)
+            .unwrap();
+
+        let path: DerivationPath = "m/44'/0'/1'".parse().unwrap();
+        let xpub = XPrv::new_from_path(SEED, &path)
+            .unwrap()
+            .public_key()
+            .to_string(Prefix::XPUB);
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"xpub":"{}","path":"m/44'/0'/1'","created_at":1700000000}}"#,
+                xpub
+            )
+        );
+
+        let child = xprv.derive_child(ChildNumber(0)).unwrap();
+        assert_eq!(
+            child.export_account_public_key_json(0, 0, 1_700_000_000),
+            Err(Error::Depth)
+        );
+    }
+
+    #[test]
+    fn sign_ethereum_transaction() {
+        // EIP-155 example transaction
+        let mut xprv = XPrv::new(SEED).unwrap();
+        *xprv.private_key_mut().unwrap() = libsecp256k1::SecretKey::parse(&[0x46; 32]).unwrap();
+        let tx_rlp = hex!(
+            "e9098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080"
+        );
+
+        let signature = xprv.sign_ethereum_transaction(&tx_rlp, 1).unwrap();
+        assert_eq!(
+            signature[..32],
+            hex!("28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276")
+        );
+        assert_eq!(
+            signature[32..64],
+            hex!("67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83")
+        );
+        // v = 37 = 0 + 1 * 2 + 35
+        assert_eq!(signature[64], 0);
+
+        assert_eq!(
+            xprv.sign_ethereum_transaction(&tx_rlp[..40], 1),
+            Err(Error::Decode)
+        );
+        assert_eq!(
+            xprv.blind().sign_ethereum_transaction(&tx_rlp, 1),
+            Err(Error::Crypto)
+        );
+    }
+
+    #[test]
+    fn sign_with_path() {
+        let root = XPrv::new(SEED).unwrap();
+        let path: DerivationPath = "m/44'/60'/0'/0/0".parse().unwrap();
+        let msg = [7u8; 32];
+
+        let signature = XPrv::sign_with_path(&root, &path, &msg).unwrap();
+        let derived = root.clone().derive_from_path(&path).unwrap();
+        assert_eq!(signature, derived.sign_batch(&[msg]).unwrap()[0]);
+
+        assert_eq!(
+            XPrv::sign_with_path(&root.blind(), &path, &msg),
+            Err(Error::Crypto)
+        );
+        assert_eq!(
+            XPrv::sign_with_path(&root.blind(), &"m/0/1".parse().unwrap(), &msg),
+            Err(Error::Crypto)
+        );
+    }
+
+    #[test]
+    fn sign_batch() {
+        let xprv = XPrv::new(SEED).unwrap();
+        let messages = [[1u8; 32], [2u8; 32]];
+        let signatures = xprv.sign_batch(&messages).unwrap();
+
+        assert_eq!(signatures.len(), 2);
+        for (message, signature) in messages.iter().zip(&signatures) {
+            let (expected, recovery_id) =
+                crate::ecdsa_sign(xprv.private_key().unwrap(), message).unwrap();
+            assert_eq!(&signature[..64], &expected[..]);
+            assert_eq!(signature[64], recovery_id);
+        }
+
+        assert_eq!(xprv.blind().sign_batch(&messages), Err(Error::Crypto));
+    }
+}
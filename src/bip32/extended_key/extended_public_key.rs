@@ -1,12 +1,16 @@
 //! Extended public keys
 
 use crate::bip32::{
-    ChildNumber, DerivationPath, Error, ExtendedKey, ExtendedKeyAttrs, ExtendedPrivateKey,
-    HmacSha512, KeyFingerprint, Prefix, PrivateKey, PublicKey, PublicKeyBytes, Result, KEY_SIZE,
+    extended_key::extended_private_key::segwit_prefix, ChildNumber, DerivationPath, Error,
+    ExtendedKey, ExtendedKeyAttrs, ExtendedPrivateKey, HmacSha512, KeyFingerprint, NetworkKind,
+    Prefix, PrivateKey, PublicKey, PublicKeyBytes, Result, ScriptType, KEY_SIZE,
 };
 use core::str::FromStr;
 use hmac::Mac;
 
+use crate::bip32::{address, BitcoinAddresses};
+use alloc::vec::Vec;
+
 #[cfg(feature = "alloc")]
 use alloc::string::{String, ToString};
 
@@ -18,7 +22,7 @@ pub type XPub = ExtendedPublicKey<libsecp256k1::PublicKey>;
 /// Generic around a [`PublicKey`] type. When the `secp256k1` feature of this
 /// crate is enabled, the [`XPub`] type provides a convenient alias for
 /// extended ECDSA/secp256k1 public keys.
-#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct ExtendedPublicKey<K: PublicKey> {
     /// Derived public key
     public_key: K,
@@ -31,6 +35,24 @@ impl<K> ExtendedPublicKey<K>
 where
     K: PublicKey,
 {
+    /// Deserialize an extended public key from its raw [`ExtendedKey::BYTE_SIZE`]
+    /// byte serialization, i.e. the Base58Check payload without the checksum.
+    ///
+    /// Skips Base58 decoding and checksum verification, for hot paths reading
+    /// keys back from trusted storage. The [`Prefix`] and public key are still
+    /// validated, and point decompression dominates the cost, so the saving
+    /// is modest: see `benches/xpub.rs`. Returns [`Error::Crypto`] for private
+    /// keys or invalid key material.
+    pub fn from_raw_bytes(bytes: &[u8; ExtendedKey::BYTE_SIZE]) -> Result<Self> {
+        let prefix = Prefix::try_from(&bytes[..Prefix::LENGTH])?;
+        ExtendedKey::read_unprefixed(prefix, &bytes[Prefix::LENGTH..])?.try_into()
+    }
+
+    /// Assemble an extended public key from its parts.
+    pub(crate) fn from_parts(public_key: K, attrs: ExtendedKeyAttrs) -> Self {
+        ExtendedPublicKey { public_key, attrs }
+    }
+
     /// Obtain the non-extended public key value `K`.
     pub fn public_key(&self) -> &K {
         &self.public_key
@@ -62,8 +84,8 @@ where
 
         let depth = self.attrs.depth.checked_add(1).ok_or(Error::Depth)?;
 
-        let mut hmac =
-            HmacSha512::new_from_slice(&self.attrs.chain_code).map_err(|_| Error::Crypto)?;
+        let mut hmac = HmacSha512::new_from_slice(self.attrs.chain_code.as_bytes())
+            .map_err(|_| Error::Crypto)?;
 
         hmac.update(&self.public_key.to_bytes());
         hmac.update(&child_number.to_bytes());
@@ -104,13 +126,220 @@ where
     }
 }
 
+impl XPub {
+    /// Number of consecutive unused addresses after which BIP44 wallets stop
+    /// scanning a chain.
+    pub const GAP_LIMIT: usize = 20;
+
+    /// Discover the used addresses of a BIP44 account.
+    ///
+    /// `self` must be the account key `m/44'/{coin}'/{account}'`. Addresses on
+    /// its external chain are derived in order and passed to `balance_fn`,
+    /// stopping after [`Self::GAP_LIMIT`] consecutive addresses for which it
+    /// returns `false`. Returns the addresses for which it returned `true`.
+    ///
+    /// Addresses are P2PKH, which is supported for the Bitcoin, testnet,
+    /// Litecoin and Dogecoin coin types; other coin types return
+    /// [`Error::CoinType`]. Returns [`Error::ChildNumber`] if `coin` or
+    /// `account` is not a valid hardened index, or if `self` is not an
+    /// account key for `account`.
+    pub fn gap_limit_scan(
+        &self,
+        balance_fn: impl Fn(&str) -> bool,
+        coin: u32,
+        account: u32,
+    ) -> Result<Vec<String>> {
+        let coin = ChildNumber::new(coin, true)?;
+        let account = ChildNumber::new(account, true)?;
+        let version = address::p2pkh_version(coin.index()).ok_or(Error::CoinType)?;
+
+        if self.attrs.depth != 3 || self.attrs.child_number != account {
+            return Err(Error::ChildNumber);
+        }
+
+        let external = self.derive_child(ChildNumber::normal(0)?)?;
+
+        Ok(external.address_discovery_scan(
+            0,
+            Self::GAP_LIMIT,
+            |child| address::p2pkh(version, &child.to_bytes()),
+            balance_fn,
+        ))
+    }
+
+    /// Discover the used addresses of a chain (e.g. the external chain
+    /// `m/44'/{coin}'/{account}'/0` of a BIP44 account).
+    ///
+    /// Children of `self` are derived in order from index `start`, turned
+    /// into addresses with `address_fn` and passed to `has_history_fn`,
+    /// stopping after `gap_limit` consecutive addresses without history or
+    /// at the end of the non-hardened range. Returns the addresses with
+    /// history. Indices which yield an invalid child key are skipped.
+    pub fn address_discovery_scan(
+        &self,
+        start: u32,
+        gap_limit: usize,
+        address_fn: impl Fn(&XPub) -> String,
+        has_history_fn: impl Fn(&str) -> bool,
+    ) -> Vec<String> {
+        let mut addresses = Vec::new();
+        let mut unused = 0;
+
+        for index in start..ChildNumber::HARDENED_FLAG {
+            if unused >= gap_limit {
+                break;
+            }
+
+            let child = match self.derive_child(ChildNumber(index)) {
+                Ok(child) => child,
+                Err(_) => continue,
+            };
+            let address = address_fn(&child);
+
+            if has_history_fn(&address) {
+                addresses.push(address);
+                unused = 0;
+            } else {
+                unused += 1;
+            }
+        }
+
+        addresses
+    }
+
+    /// Serialize this key as standard Base64 instead of Base58Check, for
+    /// REST APIs and JSON payloads: the 78-byte BIP32 serialization,
+    /// including the version bytes of `prefix`, without a checksum.
+    ///
+    /// **Note:** this is a non-standard format which Bitcoin wallets cannot
+    /// import. Use [`ExtendedPublicKey::to_string`] for interoperability.
+    pub fn to_base64(&self, prefix: Prefix) -> String {
+        let extended_key = self.to_extended_key(prefix);
+        let mut bytes = [0u8; ExtendedKey::BYTE_SIZE];
+        bytes[..Prefix::LENGTH].copy_from_slice(&prefix.to_bytes());
+        extended_key.write_unprefixed(&mut bytes[Prefix::LENGTH..]);
+
+        base64::encode(bytes)
+    }
+
+    /// Parse a key serialized with [`XPub::to_base64`].
+    ///
+    /// Returns [`Error::Decode`] if `s` is not valid Base64 of a 78-byte
+    /// extended key, and [`Error::Crypto`] if it is a private key.
+    pub fn from_base64(s: &str) -> Result<Self> {
+        let bytes = base64::decode(s).map_err(|_| Error::Decode)?;
+
+        let bytes = bytes.as_slice().try_into().map_err(|_| Error::Decode)?;
+        Self::from_raw_bytes(bytes)
+    }
+
+    /// Serialize this key with the SLIP-132 prefix for a SegWit script
+    /// type: `ypub`/`upub` for P2SH-wrapped P2WPKH (BIP49) and `zpub`/`vpub`
+    /// for native P2WPKH (BIP84), or `Ypub`/`Upub` and `Zpub`/`Vpub` for
+    /// their multi-signature P2WSH counterparts.
+    ///
+    /// Unlike [`XPrv::to_segwit_string`][`crate::bip32::XPrv::to_segwit_string`],
+    /// the script type and network must always be given, as an extended
+    /// public key does not record its derivation path. Returns
+    /// [`Error::Decode`] for non-SegWit script types.
+    pub fn to_segwit_string(
+        &self,
+        script_type: ScriptType,
+        network: NetworkKind,
+    ) -> Result<String> {
+        Ok(self.to_string(segwit_prefix(script_type, network, false)?))
+    }
+
+    /// SegWit v0 P2WPKH output script for this key: `OP_0` followed by a
+    /// push of the 20-byte HASH160 of the compressed public key.
+    ///
+    /// This is the `scriptPubKey` of P2WPKH outputs (and the redeem script
+    /// of P2SH-wrapped ones), as needed to build PSBT inputs.
+    pub fn to_p2wpkh_witness_program(&self) -> [u8; 22] {
+        address::p2wpkh_script(&self.to_bytes())
+    }
+
+    /// Bitcoin addresses of every standard single-key type for this key,
+    /// for wallet importers which must check each format while scanning.
+    ///
+    /// `mainnet` selects between Bitcoin mainnet and testnet encodings.
+    pub fn to_address_bitcoin_all_types(&self, mainnet: bool) -> BitcoinAddresses {
+        BitcoinAddresses::new(&self.to_bytes(), mainnet)
+    }
+
+    /// Output descriptor (BIP380) for the receive addresses of this account
+    /// key, e.g. `wpkh([d34db33f/84'/0'/0']xpub.../0/*)`.
+    ///
+    /// `self` must be the account key `m/{purpose}'/{coin}'/{account}'`,
+    /// where the purpose is given by `script_type`. An extended public key
+    /// does not record the fingerprint of the master key it was derived
+    /// from, so it must be passed in as `master_fingerprint`. The key is
+    /// serialized as a `tpub` for the SLIP-44 testnet coin type `1`, and as
+    /// an `xpub` otherwise.
+    ///
+    /// Returns [`Error::Depth`] if `self` is not at depth 3,
+    /// [`Error::ChildNumber`] if its child number is not hardened or `coin`
+    /// is not a valid hardened index, and [`Error::Decode`] for
+    /// multi-signature script types.
+    pub fn to_account_descriptor(
+        &self,
+        master_fingerprint: KeyFingerprint,
+        coin: u32,
+        script_type: ScriptType,
+    ) -> Result<String> {
+        let coin = ChildNumber::new(coin, true)?;
+        let account = self.attrs.child_number;
+
+        if self.attrs.depth != 3 {
+            return Err(Error::Depth);
+        }
+
+        if !account.is_hardened() {
+            return Err(Error::ChildNumber);
+        }
+
+        let prefix = if coin.index() == 1 {
+            Prefix::TPUB
+        } else {
+            Prefix::XPUB
+        };
+
+        script_type
+            .descriptor(&format!(
+                "[{:x}/{}'/{}'/{}']{}/0/*",
+                master_fingerprint,
+                script_type.purpose(),
+                coin.index(),
+                account.index(),
+                self.to_string(prefix)
+            ))
+            .ok_or(Error::Decode)
+    }
+}
+
+/// Extended public keys hold no secret material, so clones need not be
+/// zeroized. They should still be treated as confidential: together with the
+/// private key of any non-hardened child, the chain code reveals the parent
+/// private key.
+impl<K> Clone for ExtendedPublicKey<K>
+where
+    K: PublicKey + Clone,
+{
+    fn clone(&self) -> Self {
+        ExtendedPublicKey {
+            public_key: self.public_key.clone(),
+            attrs: self.attrs.clone(),
+        }
+    }
+}
+
 impl<K> From<&ExtendedPrivateKey<K>> for ExtendedPublicKey<K::PublicKey>
 where
     K: PrivateKey,
 {
     fn from(xprv: &ExtendedPrivateKey<K>) -> ExtendedPublicKey<K::PublicKey> {
         ExtendedPublicKey {
-            public_key: xprv.private_key().public_key(),
+            public_key: xprv.raw_public_key(),
             attrs: xprv.attrs().clone(),
         }
     }
@@ -144,3 +373,218 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::bip32::extended_key::bip84_seed;
+    use crate::bip32::{
+        DerivationPath, Error, ExtendedKey, NetworkKind, Prefix, ScriptType, XPrv, XPub,
+    };
+    use hex_literal::hex;
+
+    #[test]
+    fn from_raw_bytes() {
+        let xpub_base58 = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhe\
+             PY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8";
+
+        let mut bytes = [0u8; ExtendedKey::BYTE_SIZE + 4]; // with 4-byte checksum
+        bs58::decode(xpub_base58)
+            .with_check(None)
+            .into(&mut bytes)
+            .unwrap();
+
+        let raw = bytes[..ExtendedKey::BYTE_SIZE].try_into().unwrap();
+        let xpub = XPub::from_raw_bytes(raw).unwrap();
+        assert_eq!(xpub, xpub_base58.parse().unwrap());
+
+        let mut xprv_bytes = *raw;
+        xprv_bytes[..Prefix::LENGTH].copy_from_slice(&Prefix::XPRV.to_bytes());
+        assert_eq!(XPub::from_raw_bytes(&xprv_bytes), Err(Error::Crypto));
+
+        let mut invalid_key = *raw;
+        invalid_key[ExtendedKey::BYTE_SIZE - 33] = 0x05;
+        assert!(XPub::from_raw_bytes(&invalid_key).is_err());
+    }
+
+    #[test]
+    fn address_discovery_scan() {
+        let chain = XPrv::new([0u8; 32]).unwrap().public_key();
+        let address = |child: &XPub| format!("{}", child.attrs().child_number);
+        let used = |address: &str| ["2", "4", "8"].contains(&address);
+
+        assert_eq!(
+            chain.address_discovery_scan(0, 3, address, used),
+            ["2", "4"]
+        );
+        assert_eq!(
+            chain.address_discovery_scan(0, 4, address, used),
+            ["2", "4", "8"]
+        );
+        assert_eq!(chain.address_discovery_scan(3, 3, address, used), ["4"]);
+        assert!(chain.address_discovery_scan(0, 0, address, used).is_empty());
+    }
+
+    #[test]
+    fn base64() {
+        let xpub = XPrv::new([0u8; 32]).unwrap().public_key();
+        let base64 = xpub.to_base64(Prefix::XPUB);
+
+        assert!(base64.starts_with("BIiyHg"));
+        assert_eq!(XPub::from_base64(&base64).unwrap(), xpub);
+
+        let tpub = xpub.to_base64(Prefix::TPUB);
+        assert_eq!(XPub::from_base64(&tpub).unwrap(), xpub);
+
+        assert_eq!(XPub::from_base64(&base64[4..]), Err(Error::Decode));
+        assert_eq!(XPub::from_base64("not base64!"), Err(Error::Decode));
+
+        let xprv = XPrv::new([0u8; 32]).unwrap();
+        let mut bytes = [0u8; ExtendedKey::BYTE_SIZE];
+        bytes[..4].copy_from_slice(&Prefix::XPRV.to_bytes());
+        bytes[4..].copy_from_slice(
+            &xprv
+                .to_extended_key(Prefix::XPRV)
+                .strip_prefix(Prefix::XPRV)
+                .unwrap(),
+        );
+        assert_eq!(
+            XPub::from_base64(&base64::encode(bytes)),
+            Err(Error::Crypto)
+        );
+    }
+
+    #[test]
+    fn gap_limit_scan() {
+        let path: DerivationPath = "m/44'/0'/0'".parse().unwrap();
+        let account = XPrv::new_from_path(bip84_seed(), &path)
+            .unwrap()
+            .public_key();
+
+        let used_20 = account
+            .clone()
+            .derive_from_path(&"m/0/20".parse().unwrap())
+            .map(|xpub| crate::bip32::address::p2pkh(0, &xpub.to_bytes()))
+            .unwrap();
+        let used_41 = account
+            .clone()
+            .derive_from_path(&"m/0/41".parse().unwrap())
+            .map(|xpub| crate::bip32::address::p2pkh(0, &xpub.to_bytes()))
+            .unwrap();
+
+        let found = account
+            .gap_limit_scan(
+                |address| {
+                    address == "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"
+                        || address == used_20
+                        || address == used_41
+                },
+                0,
+                0,
+            )
+            .unwrap();
+
+        // m/0/41 is past the gap limit after m/0/20
+        assert_eq!(
+            found,
+            ["1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA".to_string(), used_20]
+        );
+
+        assert_eq!(
+            account.gap_limit_scan(|_| true, 60, 0),
+            Err(Error::CoinType)
+        );
+        assert_eq!(
+            account.gap_limit_scan(|_| true, 0, 1),
+            Err(Error::ChildNumber)
+        );
+        assert_eq!(
+            account.gap_limit_scan(|_| true, 1 << 31, 0),
+            Err(Error::ChildNumber)
+        );
+        assert_eq!(
+            account.gap_limit_scan(|_| true, 0, 1 << 31),
+            Err(Error::ChildNumber)
+        );
+    }
+
+    #[test]
+    fn to_segwit_string() {
+        let seed = bip84_seed();
+        let path: DerivationPath = "m/84'/0'/0'".parse().unwrap();
+        let account = XPrv::new_from_path(&seed, &path).unwrap().public_key();
+
+        // BIP84 test vector account key
+        let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+        assert_eq!(
+            account
+                .to_segwit_string(ScriptType::P2WPKH, NetworkKind::Main)
+                .unwrap(),
+            zpub
+        );
+        assert!(account
+            .to_segwit_string(ScriptType::P2SH_P2WPKH, NetworkKind::Test)
+            .unwrap()
+            .starts_with("upub"));
+        assert!(account
+            .to_segwit_string(ScriptType::P2WSH, NetworkKind::Main)
+            .unwrap()
+            .starts_with("Zpub"));
+        assert_eq!(
+            account.to_segwit_string(ScriptType::P2PKH, NetworkKind::Main),
+            Err(Error::Decode)
+        );
+    }
+
+    #[test]
+    fn to_p2wpkh_witness_program() {
+        let seed = bip84_seed();
+        let xpub = XPrv::new_from_path(&seed, &"m/84'/0'/0'/0/0".parse().unwrap())
+            .unwrap()
+            .public_key();
+
+        // BIP84 test vector: bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu
+        assert_eq!(
+            xpub.to_p2wpkh_witness_program(),
+            hex!("0014c0cebcd6c3d3ca8c75dc5ec62ebe55330ef910e2")
+        );
+    }
+
+    #[test]
+    fn to_account_descriptor() {
+        let seed = bip84_seed();
+        let master = XPrv::new(&seed).unwrap();
+        let path: DerivationPath = "m/84'/0'/0'".parse().unwrap();
+        let account = XPrv::new_from_path(&seed, &path).unwrap().public_key();
+
+        // BIP84 test vector account key
+        let xpub = "xpub6CatWdiZiodmUeTDp8LT5or8nmbKNcuyvz7WyksVFkKB4RHwCD3XyuvPEbvqAQY3rAPshWcMLoP2fMFMKHPJ4ZeZXYVUhLv1VMrjPC7PW6V";
+        assert_eq!(
+            account.to_account_descriptor(master.public_key().fingerprint(), 0, ScriptType::P2WPKH),
+            Ok(format!("wpkh([73c5da0a/84'/0'/0']{}/0/*)", xpub))
+        );
+
+        let fingerprint = master.public_key().fingerprint();
+        assert_eq!(
+            master
+                .public_key()
+                .to_account_descriptor(fingerprint, 0, ScriptType::P2WPKH),
+            Err(Error::Depth)
+        );
+
+        let unhardened = XPrv::new_from_path(&seed, &"m/84'/0'/0".parse().unwrap())
+            .unwrap()
+            .public_key();
+        assert_eq!(
+            unhardened.to_account_descriptor(fingerprint, 0, ScriptType::P2WPKH),
+            Err(Error::ChildNumber)
+        );
+        assert_eq!(
+            account.to_account_descriptor(fingerprint, 1 << 31, ScriptType::P2WPKH),
+            Err(Error::ChildNumber)
+        );
+        assert_eq!(
+            account.to_account_descriptor(fingerprint, 0, ScriptType::P2WSH),
+            Err(Error::Decode)
+        );
+    }
+}
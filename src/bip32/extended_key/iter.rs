@@ -0,0 +1,111 @@
+//! Lazy iteration over child keys (e.g. for gap-limit address scanning)
+
+use crate::bip32::{ChildNumber, Result, XPrv, XPub};
+
+/// Iterator over the children of an [`XPrv`], by increasing index.
+///
+/// Yields child keys for indices up to (but not including) [`u32::MAX`],
+/// continuing into the hardened range after the non-hardened one.
+#[derive(Clone, Debug)]
+pub struct XPrvIterator {
+    /// Key the children are derived from.
+    base: XPrv,
+
+    /// Index of the next child to derive.
+    next_index: u32,
+}
+
+impl XPrv {
+    /// Lazily derive the children of this key, starting at index `0`.
+    pub fn iter_children(&self) -> XPrvIterator {
+        XPrvIterator {
+            base: self.clone(),
+            next_index: 0,
+        }
+    }
+}
+
+impl Iterator for XPrvIterator {
+    type Item = Result<XPrv>;
+
+    fn next(&mut self) -> Option<Result<XPrv>> {
+        if self.next_index == u32::MAX {
+            return None;
+        }
+
+        let child = self.base.derive_child(ChildNumber(self.next_index));
+        self.next_index += 1;
+        Some(child)
+    }
+}
+
+/// Iterator over the children of an [`XPub`], by increasing index.
+///
+/// Hardened children cannot be derived from a public key, so this stops at
+/// the end of the non-hardened range.
+#[derive(Clone, Debug)]
+pub struct XPubIterator {
+    /// Key the children are derived from.
+    base: XPub,
+
+    /// Index of the next child to derive.
+    next_index: u32,
+}
+
+impl XPub {
+    /// Lazily derive the non-hardened children of this key, starting at
+    /// index `0`.
+    pub fn iter_children(&self) -> XPubIterator {
+        XPubIterator {
+            base: self.clone(),
+            next_index: 0,
+        }
+    }
+}
+
+impl Iterator for XPubIterator {
+    type Item = Result<XPub>;
+
+    fn next(&mut self) -> Option<Result<XPub>> {
+        if self.next_index == ChildNumber::HARDENED_FLAG {
+            return None;
+        }
+
+        let child = self.base.derive_child(ChildNumber(self.next_index));
+        self.next_index += 1;
+        Some(child)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bip32::{ChildNumber, XPrv};
+    use hex_literal::hex;
+
+    const SEED: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+
+    #[test]
+    fn iter_children() {
+        let xprv = XPrv::new(SEED).unwrap();
+        let xpub = xprv.public_key();
+
+        let children: Vec<_> = xprv.iter_children().take(3).collect();
+        let public_children: Vec<_> = xpub.iter_children().take(3).collect();
+
+        for (i, (child, public_child)) in children.iter().zip(&public_children).enumerate() {
+            let expected = xprv.derive_child(ChildNumber(i as u32)).unwrap();
+            assert_eq!(child.as_ref().unwrap(), &expected);
+            assert_eq!(public_child.as_ref().unwrap(), &expected.public_key());
+        }
+    }
+
+    #[test]
+    fn xpub_iter_stops_before_hardened() {
+        let xpub = XPrv::new(SEED).unwrap().public_key();
+        let mut iter = xpub.iter_children();
+        iter.next_index = ChildNumber::HARDENED_FLAG - 1;
+
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().is_none());
+    }
+}
@@ -1,16 +1,44 @@
 //! Parser for extended key types (i.e. `xprv` and `xpub`)
 
 pub(crate) mod attrs;
+pub(crate) mod cbor;
 pub(crate) mod extended_private_key;
 pub(crate) mod extended_public_key;
+pub(crate) mod iter;
+pub(crate) mod ur;
 
-use crate::bip32::{ChildNumber, Error, ExtendedKeyAttrs, Prefix, Result, Version, KEY_SIZE};
+use crate::bip32::{
+    ChildNumber, Error, ExtendedKeyAttrs, KeyFingerprint, NetworkKind, Prefix, Result, ScriptType,
+    Version, XPrv, XPub, KEY_SIZE,
+};
 use core::{
     fmt::{self, Display},
     str::{self, FromStr},
 };
 use zeroize::Zeroize;
 
+/// Look up the SLIP-132 [`Version`] of extended keys with the given script
+/// type and network in the [`Prefix`] registry.
+pub(crate) fn slip132_version(
+    script_type: ScriptType,
+    network: NetworkKind,
+    private: bool,
+) -> Option<Version> {
+    Prefix::REGISTRY
+        .iter()
+        .find(|(prefix, s, n)| *s == script_type && *n == network && prefix.is_private() == private)
+        .map(|(prefix, _, _)| prefix.version())
+}
+
+/// Look up the script type and network of extended keys with the given
+/// [`Version`] in the [`Prefix`] registry.
+pub(crate) fn slip132_script_type(version: Version) -> Option<(ScriptType, NetworkKind)> {
+    Prefix::REGISTRY
+        .iter()
+        .find(|(prefix, _, _)| prefix.version() == version)
+        .map(|(_, script_type, network)| (*script_type, *network))
+}
+
 /// Serialized extended key (e.g. `xprv` and `xpub`).
 #[derive(Clone)]
 pub struct ExtendedKey {
@@ -35,6 +63,55 @@ impl ExtendedKey {
     /// Note that extended keys can also be 111-bytes.
     pub const MAX_BASE58_SIZE: usize = 112;
 
+    /// Parse a Base58-encoded extended key with a SLIP-132 prefix (e.g.
+    /// `zpub`, `vprv`), returning the script type and network encoded in its
+    /// version bytes along with the key.
+    ///
+    /// Multi-signature prefixes (`Ypub`, `Zpub`, `Upub`, `Vpub` and their
+    /// private counterparts) are reported as [`ScriptType::P2SH_P2WSH`] and
+    /// [`ScriptType::P2WSH`]. Versions missing from the [`Prefix`] registry
+    /// return [`Error::Decode`].
+    pub fn from_slip132_str(s: &str) -> Result<(Self, ScriptType, NetworkKind)> {
+        let extended_key = Self::from_str(s)?;
+
+        let (script_type, network) =
+            slip132_script_type(extended_key.prefix.version()).ok_or(Error::Decode)?;
+
+        Ok((extended_key, script_type, network))
+    }
+
+    /// Check that this key is internally consistent, e.g. after loading it
+    /// from untrusted or possibly corrupted storage:
+    ///
+    /// - the prefix is a public or a private key prefix,
+    /// - a private key is a valid secp256k1 scalar from which the public key
+    ///   can be re-derived, and a public key is a valid compressed point,
+    /// - a depth 0 (master) key has a zero parent fingerprint and child
+    ///   number.
+    ///
+    /// The parent fingerprint of other keys cannot be checked without their
+    /// parent. Returns [`Error::Crypto`] if any check fails.
+    pub fn verify_integrity(&self) -> Result<()> {
+        if self.prefix.is_private() {
+            XPrv::try_from(self.clone())
+                .map_err(|_| Error::Crypto)?
+                .public_key();
+        } else if self.prefix.is_public() {
+            XPub::try_from(self.clone()).map_err(|_| Error::Crypto)?;
+        } else {
+            return Err(Error::Crypto);
+        }
+
+        if self.attrs.depth == 0
+            && (self.attrs.parent_fingerprint != KeyFingerprint::default()
+                || self.attrs.child_number != ChildNumber::default())
+        {
+            return Err(Error::Crypto);
+        }
+
+        Ok(())
+    }
+
     /// Write a Base58-encoded key to the provided buffer, returning a `&str`
     /// containing the serialized data.
     ///
@@ -43,17 +120,66 @@ impl ExtendedKey {
     pub fn write_base58<'a>(&self, buffer: &'a mut [u8; Self::MAX_BASE58_SIZE]) -> Result<&'a str> {
         let mut bytes = [0u8; Self::BYTE_SIZE]; // with 4-byte checksum
         bytes[..4].copy_from_slice(&self.prefix.to_bytes());
-        bytes[4] = self.attrs.depth;
-        bytes[5..9].copy_from_slice(&self.attrs.parent_fingerprint);
-        bytes[9..13].copy_from_slice(&self.attrs.child_number.to_bytes());
-        bytes[13..45].copy_from_slice(&self.attrs.chain_code);
-        bytes[45..78].copy_from_slice(&self.key_bytes);
+        self.write_unprefixed(&mut bytes[4..]);
 
         let base58_len = bs58::encode(&bytes).with_check().into(buffer.as_mut())?;
         bytes.zeroize();
 
         str::from_utf8(&buffer[..base58_len]).map_err(|_| Error::Base58)
     }
+
+    /// Check that this key has the `expected` prefix, and return its
+    /// serialization without the 4-byte version: depth, parent fingerprint,
+    /// child number, chain code and key.
+    ///
+    /// Returns [`Error::Decode`] if the prefix does not match.
+    ///
+    /// The returned bytes contain the key material: zeroize them after use.
+    pub fn strip_prefix(&self, expected: Prefix) -> Result<[u8; Self::BYTE_SIZE - Prefix::LENGTH]> {
+        if self.prefix != expected {
+            return Err(Error::Decode);
+        }
+
+        let mut bytes = [0u8; Self::BYTE_SIZE - Prefix::LENGTH];
+        self.write_unprefixed(&mut bytes);
+        Ok(bytes)
+    }
+
+    /// Serialize everything after the version prefix into `bytes`.
+    fn write_unprefixed(&self, bytes: &mut [u8]) {
+        bytes[0] = self.attrs.depth;
+        bytes[1..5].copy_from_slice(self.attrs.parent_fingerprint.as_bytes());
+        bytes[5..9].copy_from_slice(&self.attrs.child_number.to_bytes());
+        bytes[9..41].copy_from_slice(self.attrs.chain_code.as_bytes());
+        bytes[41..74].copy_from_slice(&self.key_bytes);
+    }
+
+    /// Parse everything after the version prefix, as serialized by
+    /// [`ExtendedKey::write_unprefixed`].
+    fn read_unprefixed(prefix: Prefix, bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::BYTE_SIZE - Prefix::LENGTH {
+            return Err(Error::Decode);
+        }
+
+        let depth = bytes[0];
+        let parent_fingerprint = bytes[1..5].try_into()?;
+        let child_number = ChildNumber::from_bytes(bytes[5..9].try_into()?);
+        let chain_code = bytes[9..41].try_into()?;
+        let key_bytes = bytes[41..74].try_into()?;
+
+        let attrs = ExtendedKeyAttrs {
+            depth,
+            parent_fingerprint,
+            child_number,
+            chain_code,
+        };
+
+        Ok(ExtendedKey {
+            prefix,
+            attrs,
+            key_bytes,
+        })
+    }
 }
 
 impl Display for ExtendedKey {
@@ -82,25 +208,9 @@ impl FromStr for ExtendedKey {
             Ok(Prefix::from_parts_unchecked(chars, version))
         })?;
 
-        let depth = bytes[4];
-        let parent_fingerprint = bytes[5..9].try_into()?;
-        let child_number = ChildNumber::from_bytes(bytes[9..13].try_into()?);
-        let chain_code = bytes[13..45].try_into()?;
-        let key_bytes = bytes[45..78].try_into()?;
+        let extended_key = Self::read_unprefixed(prefix, &bytes[4..Self::BYTE_SIZE]);
         bytes.zeroize();
-
-        let attrs = ExtendedKeyAttrs {
-            depth,
-            parent_fingerprint,
-            child_number,
-            chain_code,
-        };
-
-        Ok(ExtendedKey {
-            prefix,
-            attrs,
-            key_bytes,
-        })
+        extended_key
     }
 }
 
@@ -112,9 +222,19 @@ impl Drop for ExtendedKey {
 
 // TODO(tarcieri): consolidate test vectors
 
+/// Seed of the BIP84 test vector mnemonic, with an empty passphrase.
+#[cfg(test)]
+pub(crate) fn bip84_seed() -> crate::bip39::Seed {
+    let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    crate::bip39::Seed::new(
+        &crate::bip39::Mnemonic::from_phrase(phrase, crate::bip39::Language::English).unwrap(),
+        "",
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::bip32::Prefix;
+    use crate::bip32::{Error, NetworkKind, Prefix, ScriptType};
     use crate::{
         bip32::{ChildNumber, DerivationPath, ExtendedKey, XPrv},
         bip39::Seed,
@@ -122,6 +242,109 @@ mod tests {
     use alloc::string::ToString;
     use hex_literal::hex;
 
+    #[test]
+    fn verify_integrity() {
+        let xprv = XPrv::new_from_path([0u8; 32], &"m/0'/1".parse().unwrap()).unwrap();
+        let key = xprv.to_extended_key(Prefix::XPRV);
+        assert_eq!(key.verify_integrity(), Ok(()));
+        assert_eq!(
+            xprv.public_key()
+                .to_extended_key(Prefix::XPUB)
+                .verify_integrity(),
+            Ok(())
+        );
+
+        // Private key not below the curve order
+        let mut corrupted = key.clone();
+        corrupted.key_bytes[1..].copy_from_slice(&[0xff; 32]);
+        assert_eq!(corrupted.verify_integrity(), Err(Error::Crypto));
+
+        // Public key with a bad SEC1 tag
+        let mut corrupted = xprv.public_key().to_extended_key(Prefix::XPUB);
+        corrupted.key_bytes[0] = 0x04;
+        assert_eq!(corrupted.verify_integrity(), Err(Error::Crypto));
+
+        // Private key under a public prefix
+        let mut corrupted = key.clone();
+        corrupted.prefix = Prefix::XPUB;
+        assert_eq!(corrupted.verify_integrity(), Err(Error::Crypto));
+
+        // Master key with a parent
+        let mut corrupted = XPrv::new([0u8; 32]).unwrap().to_extended_key(Prefix::XPRV);
+        corrupted.attrs.parent_fingerprint = [1, 2, 3, 4].into();
+        assert_eq!(corrupted.verify_integrity(), Err(Error::Crypto));
+    }
+
+    #[test]
+    fn from_slip132_str() {
+        // BIP84 test vector account key
+        let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+        let (key, script_type, network) = ExtendedKey::from_slip132_str(zpub).unwrap();
+        assert_eq!(key.prefix.as_str(), "zpub");
+        assert_eq!(script_type, ScriptType::P2WPKH);
+        assert_eq!(network, NetworkKind::Main);
+
+        let tprv = XPrv::new([0u8; 32]).unwrap().to_string(Prefix::TPRV);
+        let (_, script_type, network) = ExtendedKey::from_slip132_str(&tprv).unwrap();
+        assert_eq!(script_type, ScriptType::P2PKH);
+        assert_eq!(network, NetworkKind::Test);
+
+        let multisig = [
+            ("Yprv", ScriptType::P2SH_P2WSH, NetworkKind::Main),
+            ("Ypub", ScriptType::P2SH_P2WSH, NetworkKind::Main),
+            ("Zprv", ScriptType::P2WSH, NetworkKind::Main),
+            ("Zpub", ScriptType::P2WSH, NetworkKind::Main),
+            ("Uprv", ScriptType::P2SH_P2WSH, NetworkKind::Test),
+            ("Upub", ScriptType::P2SH_P2WSH, NetworkKind::Test),
+            ("Vprv", ScriptType::P2WSH, NetworkKind::Test),
+            ("Vpub", ScriptType::P2WSH, NetworkKind::Test),
+        ];
+
+        for (chars, expected_script_type, expected_network) in multisig {
+            let (prefix, _) = Prefix::all_versions()
+                .iter()
+                .find(|(prefix, _)| prefix.as_str() == chars)
+                .unwrap();
+            assert_eq!(Prefix::try_from(prefix.version()).unwrap(), *prefix);
+
+            let mut key: ExtendedKey = zpub.parse().unwrap();
+            key.prefix = *prefix;
+            let serialized = key.to_string();
+            assert!(serialized.starts_with(chars));
+
+            let (key, script_type, network) = ExtendedKey::from_slip132_str(&serialized).unwrap();
+            assert_eq!(key.prefix, *prefix);
+            assert_eq!(script_type, expected_script_type);
+            assert_eq!(network, expected_network);
+        }
+
+        // Litecoin `Ltub` is well-formed but not in the registry
+        let mut ltub: ExtendedKey = zpub.parse().unwrap();
+        ltub.prefix = Prefix::from_bytes([0x01, 0x9d, 0xa4, 0x62]).unwrap();
+        assert!(matches!(
+            ExtendedKey::from_slip132_str(&ltub.to_string()),
+            Err(Error::Decode)
+        ));
+    }
+
+    #[test]
+    fn strip_prefix() {
+        let xpub_base58 = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhe\
+             PY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8";
+        let xpub = xpub_base58.parse::<ExtendedKey>().unwrap();
+
+        let bytes = xpub.strip_prefix(Prefix::XPUB).unwrap();
+        assert_eq!(bytes.len(), 74);
+        assert_eq!(bytes[0], 0);
+        assert_eq!(&bytes[9..41], xpub.attrs.chain_code.as_bytes());
+        assert_eq!(&bytes[41..], &xpub.key_bytes);
+
+        assert!(matches!(
+            xpub.strip_prefix(Prefix::TPUB),
+            Err(Error::Decode)
+        ));
+    }
+
     #[test]
     fn bip32_test_vector_1_xprv() {
         let xprv_base58 = "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPP\
@@ -130,11 +353,11 @@ mod tests {
         let xprv = xprv_base58.parse::<ExtendedKey>().unwrap();
         assert_eq!(xprv.prefix.as_str(), "xprv");
         assert_eq!(xprv.attrs.depth, 0);
-        assert_eq!(xprv.attrs.parent_fingerprint, [0u8; 4]);
+        assert!(xprv.attrs.parent_fingerprint.is_zero());
         assert_eq!(xprv.attrs.child_number.0, 0);
         assert_eq!(
-            xprv.attrs.chain_code,
-            hex!("873DFF81C02F525623FD1FE5167EAC3A55A049DE3D314BB42EE227FFED37D508")
+            xprv.attrs.chain_code.as_bytes(),
+            &hex!("873DFF81C02F525623FD1FE5167EAC3A55A049DE3D314BB42EE227FFED37D508")
         );
         assert_eq!(
             xprv.key_bytes,
@@ -151,11 +374,11 @@ mod tests {
         let xpub = xpub_base58.parse::<ExtendedKey>().unwrap();
         assert_eq!(xpub.prefix.as_str(), "xpub");
         assert_eq!(xpub.attrs.depth, 0);
-        assert_eq!(xpub.attrs.parent_fingerprint, [0u8; 4]);
+        assert!(xpub.attrs.parent_fingerprint.is_zero());
         assert_eq!(xpub.attrs.child_number.0, 0);
         assert_eq!(
-            xpub.attrs.chain_code,
-            hex!("873DFF81C02F525623FD1FE5167EAC3A55A049DE3D314BB42EE227FFED37D508")
+            xpub.attrs.chain_code.as_bytes(),
+            &hex!("873DFF81C02F525623FD1FE5167EAC3A55A049DE3D314BB42EE227FFED37D508")
         );
         assert_eq!(
             xpub.key_bytes,
@@ -0,0 +1,219 @@
+//! Uniform Resource (BCR-2020-005) encoding of extended keys as
+//! `ur:crypto-hdkey` strings, as scanned from QR codes by airgapped wallets.
+//!
+//! A `crypto-hdkey` usually fits in a single QR code, see
+//! [`XPub::to_ur_hdkey`]. Wallets scanning animated QR codes instead expect
+//! multi-part (fountain coded) URs, see [`XPub::ur_hdkey_encoder`] and
+//! [`XPubUrDecoder`].
+
+use crate::bip32::{Error, Result, XPub};
+use alloc::{string::String, vec::Vec};
+
+/// UR type of `crypto-hdkey`.
+const UR_TYPE: &str = "crypto-hdkey";
+
+/// UR type and scheme prefix of `crypto-hdkey`.
+const PREFIX: &str = "ur:crypto-hdkey/";
+
+impl XPub {
+    /// Encode this key as a single-part `ur:crypto-hdkey` Uniform Resource,
+    /// for display as a QR code.
+    pub fn to_ur_hdkey(&self) -> String {
+        encode(&self.to_cbor())
+    }
+
+    /// Parse a public key from a single-part `ur:crypto-hdkey` Uniform
+    /// Resource. The string is case-insensitive, as QR codes are usually
+    /// encoded in uppercase.
+    pub fn from_ur_hdkey(ur: &str) -> Result<Self> {
+        XPub::from_cbor(&decode(ur)?)
+    }
+
+    /// Encode this key as multi-part `ur:crypto-hdkey` Uniform Resources,
+    /// for display as an animated QR code. Each part carries at most
+    /// `max_fragment_length` bytes of the key.
+    ///
+    /// # Panics
+    ///
+    /// If `max_fragment_length` is 0.
+    pub fn ur_hdkey_encoder(&self, max_fragment_length: usize) -> XPubUrEncoder {
+        assert!(max_fragment_length > 0, "fragments must not be empty");
+
+        XPubUrEncoder {
+            encoder: ur::Encoder::new(&self.to_cbor(), max_fragment_length, UR_TYPE)
+                .expect("valid UR type and non-empty payload"),
+        }
+    }
+}
+
+/// Endless iterator over the parts of a multi-part `ur:crypto-hdkey`, as
+/// returned by [`XPub::ur_hdkey_encoder`].
+///
+/// The first [`XPubUrEncoder::fragment_count`] parts hold the key as is;
+/// later ones are fountain coded combinations of its fragments, so that a
+/// scanner can recover the key from whichever parts it catches.
+pub struct XPubUrEncoder {
+    /// Fountain encoder of the key's CBOR.
+    encoder: ur::Encoder<'static>,
+}
+
+impl XPubUrEncoder {
+    /// Number of fragments the key has been split into.
+    pub fn fragment_count(&self) -> usize {
+        self.encoder.fragment_count()
+    }
+}
+
+impl Iterator for XPubUrEncoder {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        Some(
+            self.encoder
+                .next_part()
+                .expect("fountain parts serialize to CBOR"),
+        )
+    }
+}
+
+/// Decoder of multi-part `ur:crypto-hdkey` Uniform Resources, as scanned
+/// from an animated QR code.
+#[derive(Default)]
+pub struct XPubUrDecoder {
+    /// Fountain decoder of the key's CBOR.
+    decoder: ur::Decoder,
+}
+
+impl XPubUrDecoder {
+    /// Create a decoder which has not received any part yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Receive a part, in any order and case. Returns the public key once
+    /// enough parts have been received to recover it.
+    pub fn receive(&mut self, part: &str) -> Result<Option<XPub>> {
+        if !part.to_ascii_lowercase().starts_with(PREFIX) {
+            return Err(Error::Decode);
+        }
+
+        self.decoder.receive(part).map_err(|_| Error::Decode)?;
+
+        match self.decoder.message().map_err(|_| Error::Decode)? {
+            Some(cbor) => XPub::from_cbor(&cbor).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Encode a CBOR payload as a single-part `crypto-hdkey` UR.
+fn encode(cbor: &[u8]) -> String {
+    ur::encode(cbor, &ur::Type::Custom(UR_TYPE))
+}
+
+/// Decode a single-part `crypto-hdkey` UR into its CBOR payload.
+fn decode(ur: &str) -> Result<Vec<u8>> {
+    if !ur.to_ascii_lowercase().starts_with(PREFIX) {
+        return Err(Error::Decode);
+    }
+
+    match ur::decode(ur) {
+        Ok((ur::ur::Kind::SinglePart, cbor)) => Ok(cbor),
+        _ => Err(Error::Decode),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode;
+    use crate::bip32::{Error, XPrv, XPub, XPubUrDecoder};
+    use hex_literal::hex;
+
+    const SEED: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+
+    #[test]
+    fn master_key_vector() {
+        let xprv = XPrv::new(SEED).unwrap();
+
+        assert_eq!(
+            encode(&xprv.to_cbor()),
+            "ur:crypto-hdkey/otadykaxhdclaevswfdmjpfswpwkahcywspsmndwmusoskprbbehetchsnpfcybbmwrhchspfxj\
+             eecaahdcxltfszmlyrtdlgmhfcnzcctvwcmkbpsftgonbgauefsehgrqzdmvodizmweemtlaybakiylat"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let xpub = XPrv::new_from_path(SEED, &"m/0'/1".parse().unwrap())
+            .unwrap()
+            .public_key();
+        let ur = xpub.to_ur_hdkey();
+
+        assert!(ur.starts_with("ur:crypto-hdkey/"));
+        assert_eq!(XPub::from_ur_hdkey(&ur).unwrap().to_cbor(), xpub.to_cbor());
+        assert_eq!(
+            XPub::from_ur_hdkey(&ur.to_uppercase()).unwrap().to_cbor(),
+            xpub.to_cbor()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let xpub = XPrv::new(SEED).unwrap().public_key();
+        let ur = xpub.to_ur_hdkey();
+
+        // Corrupted checksum
+        let mut corrupted = ur.clone();
+        corrupted.replace_range(ur.len() - 2.., "ae");
+        assert_eq!(XPub::from_ur_hdkey(&corrupted), Err(Error::Decode));
+
+        // Not a byteword
+        let mut corrupted = ur.clone();
+        corrupted.replace_range(ur.len() - 2.., "zz");
+        assert_eq!(XPub::from_ur_hdkey(&corrupted), Err(Error::Decode));
+
+        // Multi-part and wrong type
+        let multi_part = ur.replace("ur:crypto-hdkey/", "ur:crypto-hdkey/1-3/");
+        assert_eq!(XPub::from_ur_hdkey(&multi_part), Err(Error::Decode));
+        let wrong_type = ur.replace("crypto-hdkey", "crypto-seed");
+        assert_eq!(XPub::from_ur_hdkey(&wrong_type), Err(Error::Decode));
+
+        // Private keys are rejected
+        let xprv = encode(&XPrv::new(SEED).unwrap().to_cbor());
+        assert_eq!(XPub::from_ur_hdkey(&xprv), Err(Error::Decode));
+    }
+
+    #[test]
+    fn multi_part() {
+        let xpub = XPrv::new_from_path(SEED, &"m/0'/1".parse().unwrap())
+            .unwrap()
+            .public_key();
+        let mut encoder = xpub.ur_hdkey_encoder(20);
+        let fragment_count = encoder.fragment_count();
+        assert!(fragment_count > 1);
+
+        // Recover the key despite missing every other part
+        let mut decoder = XPubUrDecoder::new();
+        let decoded = encoder
+            .by_ref()
+            .step_by(2)
+            .take(10 * fragment_count)
+            .find_map(|part| {
+                assert!(part.starts_with("ur:crypto-hdkey/"));
+                decoder.receive(&part.to_uppercase()).unwrap()
+            })
+            .unwrap();
+        assert_eq!(decoded.to_cbor(), xpub.to_cbor());
+
+        // Parts of other types are rejected
+        let part = encoder.next().unwrap();
+        let mut decoder = XPubUrDecoder::new();
+        assert_eq!(
+            decoder.receive(&part.replace("crypto-hdkey", "crypto-seed")),
+            Err(Error::Decode)
+        );
+
+        // Single-part URs are not parts
+        assert_eq!(decoder.receive(&xpub.to_ur_hdkey()), Err(Error::Decode));
+    }
+}
@@ -0,0 +1,96 @@
+//! Key fingerprints
+
+use crate::bip32::{Error, Result};
+use core::fmt;
+
+/// BIP32 key fingerprint: the first 4 bytes of `RIPEMD160(SHA256(public_key))`.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct KeyFingerprint([u8; KeyFingerprint::BYTE_SIZE]);
+
+impl KeyFingerprint {
+    /// Size of a key fingerprint when encoded as bytes.
+    pub const BYTE_SIZE: usize = 4;
+
+    /// Parse a key fingerprint from the byte encoding.
+    pub fn from_bytes(bytes: [u8; Self::BYTE_SIZE]) -> Self {
+        Self(bytes)
+    }
+
+    /// Borrow the byte encoding of this key fingerprint.
+    pub fn as_bytes(&self) -> &[u8; Self::BYTE_SIZE] {
+        &self.0
+    }
+
+    /// Is this the all-zero fingerprint (i.e. the parent of a root key)?
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0u8; Self::BYTE_SIZE]
+    }
+}
+
+impl AsRef<[u8]> for KeyFingerprint {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; KeyFingerprint::BYTE_SIZE]> for KeyFingerprint {
+    fn from(bytes: [u8; KeyFingerprint::BYTE_SIZE]) -> KeyFingerprint {
+        KeyFingerprint(bytes)
+    }
+}
+
+impl From<KeyFingerprint> for [u8; KeyFingerprint::BYTE_SIZE] {
+    fn from(fingerprint: KeyFingerprint) -> [u8; KeyFingerprint::BYTE_SIZE] {
+        fingerprint.0
+    }
+}
+
+impl TryFrom<&[u8]> for KeyFingerprint {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<KeyFingerprint> {
+        Ok(KeyFingerprint(bytes.try_into()?))
+    }
+}
+
+impl fmt::LowerHex for KeyFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyFingerprint;
+    use crate::bip32::Error;
+
+    #[test]
+    fn is_zero() {
+        assert!(KeyFingerprint::default().is_zero());
+        assert!(!KeyFingerprint::from_bytes([0, 0, 0, 1]).is_zero());
+    }
+
+    #[test]
+    fn lower_hex() {
+        let fingerprint = KeyFingerprint::from_bytes([0x34, 0x42, 0x19, 0x3e]);
+        assert_eq!(format!("{:x}", fingerprint), "3442193e");
+        assert_eq!(format!("{:#x}", fingerprint), "0x3442193e");
+    }
+
+    #[test]
+    fn try_from_slice() {
+        assert_eq!(
+            KeyFingerprint::try_from(&[1u8, 2, 3, 4][..]),
+            Ok(KeyFingerprint::from_bytes([1, 2, 3, 4]))
+        );
+        assert_eq!(KeyFingerprint::try_from(&[1u8, 2][..]), Err(Error::Decode));
+    }
+}
@@ -1,34 +1,41 @@
+mod address;
+mod chain_code;
 mod child_number;
+mod derivation_cache;
 mod derivation_path;
 mod error;
 mod extended_key;
+mod key_fingerprint;
+mod network_kind;
 mod prefix;
 mod private_key;
 mod public_key;
+mod script_type;
 
+pub use address::BitcoinAddresses;
+pub use chain_code::ChainCode;
 pub use child_number::ChildNumber;
 pub use error::{Error, Result};
+pub use extended_key::iter::{XPrvIterator, XPubIterator};
+pub use extended_key::ur::{XPubUrDecoder, XPubUrEncoder};
 pub use extended_key::{
     attrs::ExtendedKeyAttrs, extended_private_key::ExtendedPrivateKey,
     extended_public_key::ExtendedPublicKey, ExtendedKey,
 };
 pub use extended_key::{extended_private_key::XPrv, extended_public_key::XPub};
+pub use key_fingerprint::KeyFingerprint;
+pub use network_kind::NetworkKind;
 pub use prefix::Prefix;
 pub use private_key::{PrivateKey, PrivateKeyBytes};
 pub use public_key::{PublicKey, PublicKeyBytes};
+pub use script_type::ScriptType;
 
+pub use derivation_cache::DerivationCache;
 pub use derivation_path::DerivationPath;
 
-/// Chain code: extension for both private and public keys which provides an
-/// additional 256-bits of entropy.
-pub type ChainCode = [u8; KEY_SIZE];
-
 /// Derivation depth.
 pub type Depth = u8;
 
-/// BIP32 key fingerprints.
-pub type KeyFingerprint = [u8; 4];
-
 /// BIP32 "versions": integer representation of the key prefix.
 pub type Version = u32;
 
@@ -38,6 +45,9 @@ type HmacSha512 = hmac::Hmac<sha2::Sha512>;
 /// Size of input key material and derived keys.
 pub const KEY_SIZE: usize = 32;
 
+/// HMAC-SHA512 key used to compute master keys from a seed.
+pub const MASTER_KEY_HMAC_KEY: &[u8] = b"Bitcoin seed";
+
 #[cfg(test)]
 mod test_mod {
     use super::*;
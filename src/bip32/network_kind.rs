@@ -0,0 +1,11 @@
+//! Network kinds
+
+/// Kind of network an extended key is encoded for.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum NetworkKind {
+    /// Bitcoin mainnet (and other production networks).
+    Main,
+
+    /// Bitcoin testnet, signet and regtest.
+    Test,
+}
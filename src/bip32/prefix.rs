@@ -1,6 +1,6 @@
 //! Extended key prefixes.
 
-use crate::bip32::{Error, ExtendedKey, Result, Version};
+use crate::bip32::{Error, ExtendedKey, NetworkKind, Result, ScriptType, Version};
 use core::{
     fmt::{self, Debug, Display},
     str,
@@ -40,6 +40,18 @@ impl Prefix {
     /// `tpub` prefix
     pub const TPUB: Self = Self::from_parts_unchecked("tpub", 0x043587cf);
 
+    /// `uprv` prefix
+    pub const UPRV: Self = Self::from_parts_unchecked("uprv", 0x044a4e28);
+
+    /// `upub` prefix
+    pub const UPUB: Self = Self::from_parts_unchecked("upub", 0x044a5262);
+
+    /// `vprv` prefix
+    pub const VPRV: Self = Self::from_parts_unchecked("vprv", 0x045f18bc);
+
+    /// `vpub` prefix
+    pub const VPUB: Self = Self::from_parts_unchecked("vpub", 0x045f1cf6);
+
     /// `xprv` prefix
     pub const XPRV: Self = Self::from_parts_unchecked("xprv", 0x0488ade4);
 
@@ -58,6 +70,88 @@ impl Prefix {
     /// `zpub` prefix
     pub const ZPUB: Self = Self::from_parts_unchecked("zpub", 0x04b24746);
 
+    /// All prefixes known to this crate (SLIP-132), along with the script
+    /// type and network of the keys they are used for.
+    ///
+    /// This is the single registry of supported extended key types: the
+    /// other lookup tables are derived from it.
+    pub(crate) const REGISTRY: [(Self, ScriptType, NetworkKind); 20] = [
+        (Self::XPRV, ScriptType::P2PKH, NetworkKind::Main),
+        (Self::XPUB, ScriptType::P2PKH, NetworkKind::Main),
+        (Self::YPRV, ScriptType::P2SH_P2WPKH, NetworkKind::Main),
+        (Self::YPUB, ScriptType::P2SH_P2WPKH, NetworkKind::Main),
+        (Self::ZPRV, ScriptType::P2WPKH, NetworkKind::Main),
+        (Self::ZPUB, ScriptType::P2WPKH, NetworkKind::Main),
+        (Self::TPRV, ScriptType::P2PKH, NetworkKind::Test),
+        (Self::TPUB, ScriptType::P2PKH, NetworkKind::Test),
+        (Self::UPRV, ScriptType::P2SH_P2WPKH, NetworkKind::Test),
+        (Self::UPUB, ScriptType::P2SH_P2WPKH, NetworkKind::Test),
+        (Self::VPRV, ScriptType::P2WPKH, NetworkKind::Test),
+        (Self::VPUB, ScriptType::P2WPKH, NetworkKind::Test),
+        (
+            Self::from_parts_unchecked("Yprv", 0x0295b005),
+            ScriptType::P2SH_P2WSH,
+            NetworkKind::Main,
+        ),
+        (
+            Self::from_parts_unchecked("Ypub", 0x0295b43f),
+            ScriptType::P2SH_P2WSH,
+            NetworkKind::Main,
+        ),
+        (
+            Self::from_parts_unchecked("Zprv", 0x02aa7a99),
+            ScriptType::P2WSH,
+            NetworkKind::Main,
+        ),
+        (
+            Self::from_parts_unchecked("Zpub", 0x02aa7ed3),
+            ScriptType::P2WSH,
+            NetworkKind::Main,
+        ),
+        (
+            Self::from_parts_unchecked("Uprv", 0x024285b5),
+            ScriptType::P2SH_P2WSH,
+            NetworkKind::Test,
+        ),
+        (
+            Self::from_parts_unchecked("Upub", 0x024289ef),
+            ScriptType::P2SH_P2WSH,
+            NetworkKind::Test,
+        ),
+        (
+            Self::from_parts_unchecked("Vprv", 0x02575048),
+            ScriptType::P2WSH,
+            NetworkKind::Test,
+        ),
+        (
+            Self::from_parts_unchecked("Vpub", 0x02575483),
+            ScriptType::P2WSH,
+            NetworkKind::Test,
+        ),
+    ];
+
+    /// All prefixes known to this crate, along with their [`Version`] numbers.
+    const ALL_VERSIONS: [(Self, Version); Self::REGISTRY.len()] = {
+        let mut versions = [(Self::XPUB, 0); Self::REGISTRY.len()];
+        let mut i = 0;
+
+        while i < versions.len() {
+            let prefix = Self::REGISTRY[i].0;
+            versions[i] = (prefix, prefix.version);
+            i += 1;
+        }
+
+        versions
+    };
+
+    /// Get all prefixes known to this crate, along with their [`Version`]
+    /// numbers.
+    ///
+    /// This is the registry of supported extended key types.
+    pub fn all_versions() -> &'static [(Self, Version)] {
+        &Self::ALL_VERSIONS
+    }
+
     /// Create a new prefix from the given 4-character string and version number.
     /// The main intended use case for this function is [`Prefix`] constants
     /// such as [`Prefix::XPRV`].
@@ -83,11 +177,29 @@ impl Prefix {
         Self::from_version(Version::from_be_bytes(bytes))
     }
 
+    /// Look up a prefix known to this crate (see [`Prefix::all_versions`])
+    /// from its encoded bytes.
+    ///
+    /// Unlike [`Prefix::from_bytes`], which accepts any version that encodes
+    /// to a well-formed prefix, this returns `None` for unrecognized versions.
+    pub fn from_known_bytes(bytes: [u8; Self::LENGTH]) -> Option<Self> {
+        let version = Version::from_be_bytes(bytes);
+
+        Self::ALL_VERSIONS
+            .iter()
+            .find(|(_, v)| *v == version)
+            .map(|(prefix, _)| *prefix)
+    }
+
     /// Parse a [`Prefix`] from a 32-bit integer "version", e.g.:
     ///
     /// - 0x0488B21E => `xpub`
     /// - 0x0488ADE4 => `xprv`
     fn from_version(version: Version) -> Result<Self> {
+        if let Some(prefix) = Self::from_known_bytes(version.to_be_bytes()) {
+            return Ok(prefix);
+        }
+
         let mut bytes = [0u8; ExtendedKey::BYTE_SIZE];
         bytes[..4].copy_from_slice(&version.to_be_bytes());
 
@@ -229,5 +341,38 @@ mod tests {
         assert_eq!(Prefix::ZPRV.as_str(), "zprv");
         assert_eq!(Prefix::ZPUB, Prefix::try_from(0x04b24746).unwrap());
         assert_eq!(Prefix::ZPUB.as_str(), "zpub");
+
+        assert_eq!(Prefix::UPRV, Prefix::try_from(0x044a4e28).unwrap());
+        assert_eq!(Prefix::UPRV.as_str(), "uprv");
+        assert_eq!(Prefix::UPUB, Prefix::try_from(0x044a5262).unwrap());
+        assert_eq!(Prefix::UPUB.as_str(), "upub");
+
+        assert_eq!(Prefix::VPRV, Prefix::try_from(0x045f18bc).unwrap());
+        assert_eq!(Prefix::VPRV.as_str(), "vprv");
+        assert_eq!(Prefix::VPUB, Prefix::try_from(0x045f1cf6).unwrap());
+        assert_eq!(Prefix::VPUB.as_str(), "vpub");
+    }
+
+    #[test]
+    fn bytes() {
+        for (prefix, _) in Prefix::all_versions() {
+            assert_eq!(Prefix::from_known_bytes(prefix.to_bytes()), Some(*prefix));
+            assert_eq!(Prefix::from_bytes(prefix.to_bytes()).unwrap(), *prefix);
+        }
+
+        // Litecoin `Ltub` is well-formed but not in the registry
+        let ltub = 0x019da462u32.to_be_bytes();
+        assert_eq!(Prefix::from_known_bytes(ltub), None);
+        assert_eq!(Prefix::from_bytes(ltub).unwrap().as_str(), "Ltub");
+    }
+
+    #[test]
+    fn all_versions() {
+        assert_eq!(Prefix::all_versions().len(), Prefix::REGISTRY.len());
+
+        for (prefix, version) in Prefix::all_versions() {
+            assert_eq!(prefix.version(), *version);
+            assert_eq!(Prefix::try_from(*version).unwrap(), *prefix);
+        }
     }
 }
@@ -26,6 +26,16 @@ pub trait PrivateKey: Sized {
 
     /// Get the [`Self::PublicKey`] that corresponds to this private key.
     fn public_key(&self) -> Self::PublicKey;
+
+    /// Sign a 32-byte message hash, returning the compact `r || s`
+    /// signature.
+    ///
+    /// The default implementation returns [`Error::Crypto`] for key types
+    /// which do not support signing.
+    fn sign_hash(&self, hash: &[u8; 32]) -> Result<[u8; 64]> {
+        let _ = hash;
+        Err(Error::Crypto)
+    }
 }
 
 impl PrivateKey for libsecp256k1::SecretKey {
@@ -53,22 +63,34 @@ impl PrivateKey for libsecp256k1::SecretKey {
     fn public_key(&self) -> Self::PublicKey {
         libsecp256k1::PublicKey::from_secret_key(self)
     }
+
+    fn sign_hash(&self, hash: &[u8; 32]) -> Result<[u8; 64]> {
+        let (signature, _) = libsecp256k1::sign(&libsecp256k1::Message::parse(hash), self);
+        Ok(signature.serialize())
+    }
 }
 
-impl From<XPrv> for libsecp256k1::SecretKey {
-    fn from(xprv: XPrv) -> libsecp256k1::SecretKey {
-        libsecp256k1::SecretKey::from(&xprv)
+/// NOTE: fails with [`Error::Crypto`] if the key has been blinded.
+impl TryFrom<XPrv> for libsecp256k1::SecretKey {
+    type Error = Error;
+
+    fn try_from(xprv: XPrv) -> Result<libsecp256k1::SecretKey> {
+        libsecp256k1::SecretKey::try_from(&xprv)
     }
 }
 
-impl From<&XPrv> for libsecp256k1::SecretKey {
-    fn from(xprv: &XPrv) -> libsecp256k1::SecretKey {
-        *xprv.private_key()
+/// NOTE: fails with [`Error::Crypto`] if the key has been blinded.
+impl TryFrom<&XPrv> for libsecp256k1::SecretKey {
+    type Error = Error;
+
+    fn try_from(xprv: &XPrv) -> Result<libsecp256k1::SecretKey> {
+        xprv.private_key().copied()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::bip32::{Error, PrivateKey};
     use hex_literal::hex;
 
     //type XPrv = crate::bip32::ExtendedPrivateKey<k256::ecdsa::SigningKey>;
@@ -90,4 +112,40 @@ mod tests {
             "xprvA2nrNbFZABcdryreWet9Ea4LvTJcGsqrMzxHx98MMrotbir7yrKCEXw7nadnHM8Dq38EGfSh6dqA9QWTyefMLEcBYJUuekgW4BYPJcr9E7j".parse().unwrap()
         );
     }
+
+    #[test]
+    fn secp256k1_private_key_mut() {
+        let mut xprv = XPrv::new([0u8; 32]).unwrap();
+        let tweak = libsecp256k1::SecretKey::default();
+        let mut expected = *xprv.private_key().unwrap();
+        expected.tweak_add_assign(&tweak).unwrap();
+
+        xprv.private_key_mut()
+            .unwrap()
+            .tweak_add_assign(&tweak)
+            .unwrap();
+        assert_eq!(xprv.private_key().unwrap(), &expected);
+    }
+
+    #[test]
+    fn secp256k1_sign_hash() {
+        let xprv = XPrv::new([0u8; 32]).unwrap();
+        let hash = [7u8; 32];
+
+        let signature = xprv.private_key().unwrap().sign_hash(&hash).unwrap();
+        let (expected, _) = crate::ecdsa_sign(xprv.private_key().unwrap(), &hash).unwrap();
+        assert_eq!(&signature[..], &expected[..]);
+    }
+
+    #[test]
+    fn secp256k1_blinded() {
+        let blinded = XPrv::new([0u8; 32]).unwrap().blind();
+
+        assert_eq!(blinded.private_key().err(), Some(Error::Crypto));
+        assert_eq!(blinded.clone().private_key_mut().err(), Some(Error::Crypto));
+        assert_eq!(
+            libsecp256k1::SecretKey::try_from(&blinded).err(),
+            Some(Error::Crypto)
+        );
+    }
 }
@@ -29,7 +29,20 @@ pub trait PublicKey: Sized {
     /// Default implementation uses `RIPEMD160(SHA256(public_key))`.
     fn fingerprint(&self) -> KeyFingerprint {
         let digest = Ripemd160::digest(Sha256::digest(self.to_bytes()));
-        digest[..4].try_into().expect("digest truncated")
+        digest[..KeyFingerprint::BYTE_SIZE]
+            .try_into()
+            .expect("digest truncated")
+    }
+
+    /// Verify a compact `r || s` signature over a 32-byte message hash.
+    ///
+    /// Returns `Ok(false)` for a well-formed signature which does not verify,
+    /// and [`Error::Crypto`] if the signature is malformed. The default
+    /// implementation returns [`Error::Crypto`] for key types which do not
+    /// support verification.
+    fn verify_hash(&self, hash: &[u8; 32], sig: &[u8; 64]) -> Result<bool> {
+        let _ = (hash, sig);
+        Err(Error::Crypto)
     }
 }
 
@@ -52,6 +65,15 @@ impl PublicKey for libsecp256k1::PublicKey {
             Err(_) => Err(Error::Crypto),
         }
     }
+
+    fn verify_hash(&self, hash: &[u8; 32], sig: &[u8; 64]) -> Result<bool> {
+        let signature = libsecp256k1::Signature::parse_standard(sig).map_err(|_| Error::Crypto)?;
+        Ok(libsecp256k1::verify(
+            &libsecp256k1::Message::parse(hash),
+            &signature,
+            self,
+        ))
+    }
 }
 
 impl From<XPub> for libsecp256k1::PublicKey {
@@ -66,9 +88,17 @@ impl From<&XPub> for libsecp256k1::PublicKey {
     }
 }
 
+impl AsRef<libsecp256k1::PublicKey> for XPub {
+    fn as_ref(&self) -> &libsecp256k1::PublicKey {
+        self.public_key()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::bip32::{Error, PrivateKey, PublicKey};
     use hex_literal::hex;
+    use rand::RngCore;
 
     const SEED: [u8; 64] = hex!(
         "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a2
@@ -99,4 +129,38 @@ mod tests {
             "xpub6FnCn6nSzZAw5Tw7cgR9bi15UV96gLZhjDstkXXxvCLsUXBGXPdSnLFbdpq8p9HmGsApME5hQTZ3emM2rnY5agb9rXpVGyy3bdW6EEgAtqt".parse().unwrap()
         );
     }
+
+    #[test]
+    fn secp256k1_as_ref() {
+        let xpub = XPrv::new(SEED).unwrap().public_key();
+        let public_key: &libsecp256k1::PublicKey = xpub.as_ref();
+        assert_eq!(public_key, &libsecp256k1::PublicKey::from(&xpub));
+    }
+
+    #[test]
+    fn secp256k1_sign_and_verify_hash() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..16 {
+            let mut seed = [0u8; 32];
+            let mut hash = [0u8; 32];
+            rng.fill_bytes(&mut seed);
+            rng.fill_bytes(&mut hash);
+
+            let xprv = XPrv::new(seed).unwrap();
+            let public_key = *xprv.public_key().public_key();
+            let signature = xprv.private_key().unwrap().sign_hash(&hash).unwrap();
+
+            assert_eq!(public_key.verify_hash(&hash, &signature), Ok(true));
+
+            hash[0] ^= 1;
+            assert_eq!(public_key.verify_hash(&hash, &signature), Ok(false));
+        }
+
+        let public_key = *XPrv::new(SEED).unwrap().public_key().public_key();
+        assert_eq!(
+            public_key.verify_hash(&[0u8; 32], &[0xff; 64]),
+            Err(Error::Crypto)
+        );
+    }
 }
@@ -0,0 +1,57 @@
+//! Output script types
+
+/// Script type of the outputs a key is used for.
+///
+/// Each script type has its own BIP44-style purpose: BIP44 for P2PKH, BIP49
+/// for P2SH-wrapped P2WPKH, BIP84 for P2WPKH and BIP86 for P2TR. The
+/// multi-signature P2WSH script types share the BIP48 purpose.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum ScriptType {
+    /// Pay to public key hash (legacy).
+    P2PKH,
+
+    /// Pay to witness public key hash (native SegWit).
+    P2WPKH,
+
+    /// Pay to witness public key hash nested in pay to script hash.
+    P2SH_P2WPKH,
+
+    /// Pay to taproot (single key, no script path).
+    P2TR,
+
+    /// Pay to witness script hash (native SegWit multi-signature).
+    P2WSH,
+
+    /// Pay to witness script hash nested in pay to script hash.
+    P2SH_P2WSH,
+}
+
+impl ScriptType {
+    /// Purpose (first hardened path component) of accounts for this script
+    /// type.
+    pub fn purpose(self) -> u32 {
+        match self {
+            ScriptType::P2PKH => 44,
+            ScriptType::P2WPKH => 84,
+            ScriptType::P2SH_P2WPKH => 49,
+            ScriptType::P2TR => 86,
+            ScriptType::P2WSH | ScriptType::P2SH_P2WSH => 48,
+        }
+    }
+
+    /// Wrap a key expression in the output descriptor (BIP380) for this
+    /// script type.
+    ///
+    /// Returns `None` for multi-signature script types, whose descriptors
+    /// need the keys of every signer.
+    pub(crate) fn descriptor(self, key: &str) -> Option<alloc::string::String> {
+        match self {
+            ScriptType::P2PKH => Some(format!("pkh({})", key)),
+            ScriptType::P2WPKH => Some(format!("wpkh({})", key)),
+            ScriptType::P2SH_P2WPKH => Some(format!("sh(wpkh({}))", key)),
+            ScriptType::P2TR => Some(format!("tr({})", key)),
+            ScriptType::P2WSH | ScriptType::P2SH_P2WSH => None,
+        }
+    }
+}
@@ -10,7 +10,7 @@ use self::rand::{thread_rng, RngCore};
 use hmac::Hmac;
 use sha2::Digest;
 
-const PBKDF2_ROUNDS: u32 = 2048;
+pub(crate) const PBKDF2_ROUNDS: u32 = 2048;
 const PBKDF2_BYTES: usize = 64;
 
 /// SHA256 helper function, internal to the crate
@@ -29,6 +29,12 @@ pub(crate) fn gen_random_bytes(byte_length: usize) -> Vec<u8> {
 
     bytes
 }
+
+/// Overwrite a buffer with random bytes, used when securely deleting secrets
+pub(crate) fn fill_random_bytes(bytes: &mut [u8]) {
+    thread_rng().fill_bytes(bytes);
+}
+
 /// PBKDF2 helper, used to generate [`Seed`][Seed] from [`Mnemonic`][Mnemonic]
 ///
 /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
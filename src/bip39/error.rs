@@ -13,6 +13,8 @@ pub enum ErrorKind {
     InvalidWordLength(usize),
     #[error("invalid entropy length {0}bits for mnemonic type {1:?}")]
     InvalidEntropyLength(usize, MnemonicType),
+    #[error("entropy matches a known weak pattern")]
+    WeakEntropy,
 }
 
 #[cfg(test)]
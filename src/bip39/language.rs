@@ -166,6 +166,63 @@ impl Language {
         }
     }
 
+    /// Canonical URL of the BIP39 word list for this language, for interoperability testing and
+    /// documentation
+    pub fn bip39_wordlist_url(&self) -> &'static str {
+        match *self {
+            Language::English => {
+                "https://raw.githubusercontent.com/trezor/python-mnemonic/master/src/mnemonic/wordlist/english.txt"
+            }
+            Language::ChineseSimplified => {
+                "https://raw.githubusercontent.com/trezor/python-mnemonic/master/src/mnemonic/wordlist/chinese_simplified.txt"
+            }
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => {
+                "https://raw.githubusercontent.com/trezor/python-mnemonic/master/src/mnemonic/wordlist/chinese_traditional.txt"
+            }
+            #[cfg(feature = "french")]
+            Language::French => {
+                "https://raw.githubusercontent.com/trezor/python-mnemonic/master/src/mnemonic/wordlist/french.txt"
+            }
+            #[cfg(feature = "italian")]
+            Language::Italian => {
+                "https://raw.githubusercontent.com/trezor/python-mnemonic/master/src/mnemonic/wordlist/italian.txt"
+            }
+            #[cfg(feature = "japanese")]
+            Language::Japanese => {
+                "https://raw.githubusercontent.com/trezor/python-mnemonic/master/src/mnemonic/wordlist/japanese.txt"
+            }
+            #[cfg(feature = "korean")]
+            Language::Korean => {
+                "https://raw.githubusercontent.com/trezor/python-mnemonic/master/src/mnemonic/wordlist/korean.txt"
+            }
+            #[cfg(feature = "spanish")]
+            Language::Spanish => {
+                "https://raw.githubusercontent.com/trezor/python-mnemonic/master/src/mnemonic/wordlist/spanish.txt"
+            }
+        }
+    }
+
+    /// BIP85 language code, used as a path component when deriving child mnemonics
+    pub(crate) fn bip85_code(&self) -> u32 {
+        match *self {
+            Language::English => 0,
+            #[cfg(feature = "japanese")]
+            Language::Japanese => 1,
+            #[cfg(feature = "korean")]
+            Language::Korean => 2,
+            #[cfg(feature = "spanish")]
+            Language::Spanish => 3,
+            Language::ChineseSimplified => 4,
+            #[cfg(feature = "chinese-traditional")]
+            Language::ChineseTraditional => 5,
+            #[cfg(feature = "french")]
+            Language::French => 6,
+            #[cfg(feature = "italian")]
+            Language::Italian => 7,
+        }
+    }
+
     /// Get the word list for this language
     pub fn wordlist(&self) -> &'static WordList {
         match *self {
@@ -186,6 +243,13 @@ impl Language {
         }
     }
 
+    /// Check whether a single word is in the word list for this language
+    ///
+    /// The word must be in the same normalization form as the word list (NFKD).
+    pub fn wordlist_contains(&self, word: &str) -> bool {
+        self.wordmap().get_bits(word).is_ok()
+    }
+
     /// Get a [`WordMap`][WordMap] that allows word -> index lookups in the word list
     ///
     /// The index of an individual word in the word list is used as the binary value of that word
@@ -242,6 +306,32 @@ mod test {
         assert!(res.is_empty());
     }
 
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn wordlist_contains() {
+        assert!(Language::English.wordlist_contains("abandon"));
+        assert!(Language::English.wordlist_contains("zoo"));
+        assert!(!Language::English.wordlist_contains("woof"));
+        assert!(!Language::English.wordlist_contains("Abandon"));
+        assert!(!Language::English.wordlist_contains(""));
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn bip39_wordlist_url() {
+        const BASE: &str =
+            "https://raw.githubusercontent.com/trezor/python-mnemonic/master/src/mnemonic/wordlist/";
+
+        assert_eq!(
+            Language::English.bip39_wordlist_url(),
+            format!("{}english.txt", BASE)
+        );
+        assert_eq!(
+            Language::ChineseSimplified.bip39_wordlist_url(),
+            format!("{}chinese_simplified.txt", BASE)
+        );
+    }
+
     fn is_wordlist_nfkd(wl: &WordList) -> bool {
         for idx in 0..2047 {
             let word = wl.get_word(idx.into());
@@ -1,15 +1,18 @@
-use super::crypto::{gen_random_bytes, sha256_first_byte};
+use super::crypto::{fill_random_bytes, gen_random_bytes, sha256_first_byte};
 use super::util::{checksum, BitWriter, IterExt};
 use super::ErrorKind;
 use super::Language;
 use super::MnemonicType;
+use super::Seed;
+use crate::bip32::{DerivationPath, XPrv};
 use anyhow::Error;
 use encoding::codec::simpchinese::*;
 use encoding::Encoding;
+use hmac::{Hmac, Mac};
 use std::fmt;
 use std::mem;
 use unicode_normalization::UnicodeNormalization;
-use zeroize::Zeroizing;
+use zeroize::{Zeroize, Zeroizing};
 
 /// The primary type in this crate, most tasks require creating or using one.
 ///
@@ -43,6 +46,20 @@ pub struct Mnemonic {
     entropy: Zeroizing<Vec<u8>>,
 }
 
+/// Diagnostic information about a mnemonic phrase, returned by
+/// [`Mnemonic::validate_with_stats()`][Mnemonic::validate_with_stats()]
+///
+/// [Mnemonic::validate_with_stats()]: ./mnemonic/struct.Mnemonic.html#method.validate_with_stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MnemonicValidationStats {
+    /// Number of words in the phrase
+    pub word_count: usize,
+    /// Number of words which were not already in Unicode NFKD form
+    pub normalized_words: usize,
+    /// Whether the phrase checksum is valid
+    pub checksum_valid: bool,
+}
+
 impl Mnemonic {
     /// Generates a new [`Mnemonic`][Mnemonic]
     ///
@@ -91,15 +108,95 @@ impl Mnemonic {
         Ok(Self::from_entropy_unchecked(entropy, lang))
     }
 
+    /// Derive a child [`Mnemonic`][Mnemonic] from a root key as specified by BIP85, so that
+    /// independent wallets can be backed up by the root's phrase alone.
+    ///
+    /// The entropy is derived at `m/83696968'/39'/{language}'/{words}'/{index}'`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kms::bip32::XPrv;
+    /// use kms::bip39::{Language, Mnemonic, MnemonicType};
+    ///
+    /// let root: XPrv = "xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb".parse().unwrap();
+    /// let child = Mnemonic::from_bip85_index(&root, Language::English, MnemonicType::Words12, 0).unwrap();
+    ///
+    /// assert_eq!("girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose", child.phrase());
+    /// ```
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    pub fn from_bip85_index(
+        root_xprv: &XPrv,
+        lang: Language,
+        words: MnemonicType,
+        index: u32,
+    ) -> Result<Mnemonic, Error> {
+        let path: DerivationPath = format!(
+            "m/83696968'/39'/{}'/{}'/{}'",
+            lang.bip85_code(),
+            words.word_count(),
+            index
+        )
+        .parse()?;
+
+        let mut derived = root_xprv.clone().derive_from_path(&path)?;
+        let private_key = Zeroizing::new(derived.to_bytes());
+        derived.zeroize();
+
+        let mut hmac = Hmac::<sha2::Sha512>::new_from_slice(b"bip-entropy-from-k")?;
+        hmac.update(&private_key[..]);
+        let entropy = Zeroizing::new(<[u8; 64]>::from(hmac.finalize().into_bytes()));
+
+        Ok(Self::from_entropy_unchecked(
+            &entropy[..words.entropy_bits() / 8],
+            lang,
+        ))
+    }
+
     fn from_entropy_unchecked<E>(entropy: E, lang: Language) -> Mnemonic
     where
         E: Into<Vec<u8>>,
     {
         let entropy = Zeroizing::new(entropy.into());
-        let wordlist = lang.wordlist();
-
         let checksum_byte = sha256_first_byte(&entropy);
 
+        Self::from_entropy_and_checksum(entropy, checksum_byte, lang)
+    }
+
+    /// Create a [`Mnemonic`][Mnemonic] with a phrase which is not valid: the first checksum bit
+    /// is flipped, for testing how invalid checksums are handled
+    ///
+    /// # Safety
+    ///
+    /// This is *logically* unsafe, not memory unsafe: every other constructor guarantees that a
+    /// `Mnemonic` holds a valid phrase, and code using the result may rely on that. Never let the
+    /// result leave a test.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entropy length is not valid for any [`MnemonicType`][MnemonicType].
+    ///
+    /// [Mnemonic]: ../mnemonic/struct.Mnemonic.html
+    /// [MnemonicType]: ../mnemonic_type/struct.MnemonicType.html
+    #[cfg(any(test, feature = "test-utils"))]
+    pub unsafe fn from_entropy_with_bad_checksum(entropy: &[u8], lang: Language) -> Mnemonic {
+        MnemonicType::for_key_size(entropy.len() * 8).expect("invalid entropy length");
+
+        let entropy = Zeroizing::new(entropy.to_vec());
+        // The checksum is taken from the most significant bits of this byte
+        let checksum_byte = sha256_first_byte(&entropy) ^ 0x80;
+
+        Self::from_entropy_and_checksum(entropy, checksum_byte, lang)
+    }
+
+    fn from_entropy_and_checksum(
+        entropy: Zeroizing<Vec<u8>>,
+        checksum_byte: u8,
+        lang: Language,
+    ) -> Mnemonic {
+        let wordlist = lang.wordlist();
+
         // First, create a byte iterator for the given entropy and the first byte of the
         // hash of the entropy that will serve as the checksum (up to 8 bits for biggest
         // entropy source).
@@ -184,6 +281,101 @@ impl Mnemonic {
         Ok(())
     }
 
+    /// Validate a mnemonic phrase and reject it if its entropy follows a known weak pattern
+    ///
+    /// In addition to the checks done by [`Mnemonic::validate()`][Mnemonic::validate()], this
+    /// returns [`ErrorKind::WeakEntropy`][ErrorKind::WeakEntropy] if the entropy is a short
+    /// repeating pattern (such as all zeros, all ones or a single repeated byte) or a run of
+    /// sequential bytes. Freshly generated mnemonics should pass this check; failing it suggests a
+    /// broken random number generator or a phrase copied from documentation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kms::bip39::{Mnemonic, Language};
+    ///
+    /// let weak = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    ///
+    /// assert!(Mnemonic::validate(weak, Language::English).is_ok());
+    /// assert!(Mnemonic::validate_against_known_weak_phrases(weak, Language::English).is_err());
+    /// ```
+    ///
+    /// [Mnemonic::validate()]: ./mnemonic/struct.Mnemonic.html#method.validate
+    /// [ErrorKind::WeakEntropy]: ../error/enum.ErrorKind.html#variant.WeakEntropy
+    pub fn validate_against_known_weak_phrases(phrase: &str, lang: Language) -> Result<(), Error> {
+        let entropy = Zeroizing::new(Mnemonic::phrase_to_entropy(phrase, lang)?);
+
+        // Repeating patterns of up to 4 bytes, which includes a single repeated byte
+        let repeating =
+            (1..=4).any(|period| entropy.iter().zip(&entropy[period..]).all(|(a, b)| a == b));
+
+        // Ascending or descending runs of bytes
+        let sequential = [1u8, u8::MAX].iter().any(|step| {
+            entropy
+                .windows(2)
+                .all(|pair| pair[0].wrapping_add(*step) == pair[1])
+        });
+
+        if repeating || sequential {
+            return Err(ErrorKind::WeakEntropy.into());
+        }
+
+        Ok(())
+    }
+
+    /// Validate a mnemonic phrase, reporting how it was processed
+    ///
+    /// This is a diagnostic tool for cross-platform compatibility issues (e.g. phrases entered
+    /// in a different Unicode normalization form), not for production key generation. Unlike
+    /// [`Mnemonic::validate()`][Mnemonic::validate()], an invalid checksum is reported in the
+    /// returned stats rather than as an error; unknown words and invalid word counts are still
+    /// errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kms::bip39::{Mnemonic, Language};
+    ///
+    /// let test_mnemonic = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    ///
+    /// let stats = Mnemonic::validate_with_stats(test_mnemonic, Language::English).unwrap();
+    ///
+    /// assert_eq!(stats.word_count, 12);
+    /// assert_eq!(stats.normalized_words, 0);
+    /// assert!(stats.checksum_valid);
+    /// ```
+    ///
+    /// [Mnemonic::validate()]: ./mnemonic/struct.Mnemonic.html#method.validate
+    pub fn validate_with_stats(
+        phrase: &str,
+        lang: Language,
+    ) -> Result<MnemonicValidationStats, Error> {
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        let normalized_words = words
+            .iter()
+            .filter(|word| !unicode_normalization::is_nfkd(word))
+            .count();
+
+        let phrase = Zeroizing::new(words.iter().map(|w| w.nfkd()).join::<String>(" "));
+
+        let checksum_valid = match Mnemonic::phrase_to_entropy(&phrase, lang) {
+            Ok(entropy) => {
+                drop(Zeroizing::new(entropy));
+                true
+            }
+            Err(err) => match err.downcast_ref::<ErrorKind>() {
+                Some(ErrorKind::InvalidChecksum) => false,
+                _ => return Err(err),
+            },
+        };
+
+        Ok(MnemonicValidationStats {
+            word_count: words.len(),
+            normalized_words,
+            checksum_valid,
+        })
+    }
+
     /// Calculate the checksum, verify it and return the entropy
     ///
     /// Only intended for internal use, as returning a `Vec<u8>` that looks a bit like it could be
@@ -229,13 +421,192 @@ impl Mnemonic {
         &self.phrase
     }
 
+    /// Get the mnemonic phrase as UTF-8 bytes.
+    ///
+    /// Unlike [`Mnemonic::as_bytes()`][Mnemonic::as_bytes()], this never re-encodes the phrase
+    /// and does not allocate.
+    ///
+    /// [Mnemonic::as_bytes()]: ./mnemonic/struct.Mnemonic.html#method.as_bytes
+    pub fn phrase_bytes(&self) -> &[u8] {
+        self.phrase.as_bytes()
+    }
+
+    /// Encode the phrase in the SeedQR numeric format used by SeedSigner: the wordlist index of
+    /// each word as a 4-digit decimal number, concatenated.
+    ///
+    /// The result is as sensitive as the phrase itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kms::bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// assert!(mnemonic.to_numeric_phrase().starts_with("12821452"));
+    /// ```
+    pub fn to_numeric_phrase(&self) -> String {
+        let wordmap = self.lang.wordmap();
+
+        self.phrase
+            .split(' ')
+            .map(|word| {
+                let bits = wordmap.get_bits(word).expect("phrase was validated");
+                format!("{:04}", u16::from(bits))
+            })
+            .collect()
+    }
+
+    /// Parse a phrase from the SeedQR numeric format produced by
+    /// [`Mnemonic::to_numeric_phrase()`][Mnemonic::to_numeric_phrase()].
+    ///
+    /// The phrase is validated the same way as by [`Mnemonic::from_phrase()`][Mnemonic::from_phrase()].
+    ///
+    /// [Mnemonic::to_numeric_phrase()]: ./mnemonic/struct.Mnemonic.html#method.to_numeric_phrase
+    pub fn from_numeric_phrase(s: &str, lang: Language) -> Result<Mnemonic, Error> {
+        if !s.len().is_multiple_of(4) {
+            Err(ErrorKind::InvalidWordLength(s.len() / 4))?;
+        }
+
+        let wordlist = lang.wordlist();
+        let phrase = Zeroizing::new(
+            s.as_bytes()
+                .chunks(4)
+                .map(|digits| {
+                    let index = std::str::from_utf8(digits)
+                        .ok()
+                        .filter(|digits| digits.bytes().all(|b| b.is_ascii_digit()))
+                        .and_then(|digits| digits.parse::<u16>().ok())
+                        .filter(|index| *index < 2048)
+                        .ok_or(ErrorKind::InvalidWord)?;
+                    Ok(wordlist.get_word(index.into()))
+                })
+                .collect::<Result<Vec<_>, ErrorKind>>()?
+                .join(" "),
+        );
+
+        Mnemonic::from_phrase(&phrase, lang)
+    }
+
+    /// Format the phrase for backup display in a terminal
+    ///
+    /// Words are numbered and laid out in lines of 4. With the `colors` feature enabled, the
+    /// lines alternate between cyan and yellow using the `colored` crate, which leaves them
+    /// plain when colors are turned off (e.g. with `NO_COLOR`); otherwise plain text is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kms::bip39::{Mnemonic, Language};
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// println!("{}", mnemonic.to_colored_display());
+    /// ```
+    pub fn to_colored_display(&self) -> String {
+        let words: Vec<&str> = self.phrase.split(' ').collect();
+
+        words
+            .chunks(4)
+            .enumerate()
+            .map(|(group, chunk)| {
+                let line = chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(i, word)| format!("{:>2}. {:<8}", group * 4 + i + 1, word))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                color_line(line.trim_end(), group)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Format the phrase as a numbered grid with the given number of columns, for printing on
+    /// paper backup cards
+    ///
+    /// Words are numbered down the columns, as on most recovery sheets, and numbers and words
+    /// are padded so the columns line up. A 12-word phrase in 3 columns gives 4 rows:
+    ///
+    /// ```text
+    ///  1. crop      5. eight     9. frame
+    ///  2. cash      6. faith    10. loud
+    ///  3. unable    7. inflict  11. box
+    ///  4. insane    8. route    12. vibrant
+    /// ```
+    ///
+    /// The grid always has exactly `columns` columns, unless the phrase has fewer words. When
+    /// the words don't divide evenly, the leftmost columns hold one more word than the others.
+    /// A `columns` value of 0 is treated as 1.
+    pub fn as_numbered_grid(&self, columns: usize) -> String {
+        let words: Vec<&str> = self.phrase.split(' ').collect();
+        let columns = columns.clamp(1, words.len());
+        let (short_rows, long_columns) = (words.len() / columns, words.len() % columns);
+        let rows = short_rows + (long_columns > 0) as usize;
+        let number_width = words.len().to_string().len();
+        let word_width = words.iter().map(|w| w.chars().count()).max().unwrap_or(0);
+
+        (0..rows)
+            .map(|row| {
+                let line = (0..columns)
+                    .filter(|&column| row < short_rows || column < long_columns)
+                    .map(|column| row + column * short_rows + column.min(long_columns))
+                    .map(|i| {
+                        format!(
+                            "{:>nw$}. {:<ww$}",
+                            i + 1,
+                            words[i],
+                            nw = number_width,
+                            ww = word_width
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("  ");
+                line.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Consume the `Mnemonic` and return the phrase as a `String`.
+    ///
+    /// The returned `String` is **not** zeroed when dropped; prefer
+    /// [`Mnemonic::into_zeroized_phrase()`][Mnemonic::into_zeroized_phrase()] unless the phrase is
+    /// handed to an API which needs a plain `String`.
+    ///
+    /// [Mnemonic::into_zeroized_phrase()]: ./mnemonic/struct.Mnemonic.html#method.into_zeroized_phrase
     pub fn into_phrase(mut self) -> String {
         // Create an empty string and swap values with the mnemonic's phrase.
         // This allows `Mnemonic` to implement `Drop`, while still returning the phrase.
         mem::take(&mut self.phrase)
     }
 
+    /// Consume the `Mnemonic` and return the phrase, which will be zeroed when dropped.
+    pub fn into_zeroized_phrase(mut self) -> Zeroizing<String> {
+        Zeroizing::new(mem::take(&mut *self.phrase))
+    }
+
+    /// Consume the `Mnemonic`, explicitly zeroing and then overwriting the phrase and entropy
+    /// buffers with random bytes before dropping them.
+    ///
+    /// Dropping a `Mnemonic` already zeroes both buffers; use this where policy requires secrets
+    /// to be wiped more than once.
+    pub fn secure_delete(mut self) {
+        // Defense in depth against the compiler optimizing away the zeroing path: `Zeroizing`
+        // already wipes the buffers with volatile writes on drop, but here each buffer is zeroed,
+        // then filled with random bytes across its whole capacity, then zeroed again on drop. If
+        // any one pass were ever elided, the others still leave no trace of the secret.
+        let mut phrase = Zeroizing::new(mem::take(&mut *self.phrase).into_bytes());
+        let mut entropy = Zeroizing::new(mem::take(&mut *self.entropy));
+
+        wipe(&mut phrase);
+        wipe(&mut entropy);
+    }
+
     /// Get the original entropy value of the mnemonic phrase as a slice.
     ///
     /// # Example
@@ -256,6 +627,38 @@ impl Mnemonic {
         &self.entropy
     }
 
+    /// Generate the seed for this mnemonic without a passphrase, as a fixed-size array
+    ///
+    /// This is the same value as [`Seed::new(&mnemonic, "")`][Seed::new()], without requiring
+    /// a heap allocated [`Seed`][Seed] to be kept around.
+    ///
+    /// Unlike [`Seed`][Seed], the returned array is not zeroed automatically: call `zeroize()`
+    /// on it (from the [`Zeroize`][Zeroize] trait) once you are done with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kms::bip39::{Mnemonic, Language};
+    /// use zeroize::Zeroize;
+    ///
+    /// let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+    /// let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+    ///
+    /// let mut seed = mnemonic.to_seed_array();
+    /// // ... use the seed ...
+    /// seed.zeroize();
+    /// ```
+    ///
+    /// [Seed]: ../seed/struct.Seed.html
+    /// [Seed::new()]: ../seed/struct.Seed.html#method.new
+    /// [Zeroize]: https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html
+    pub fn to_seed_array(&self) -> [u8; 64] {
+        let seed = Seed::new(self, "");
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(seed.as_bytes());
+        bytes
+    }
+
     /// Get the [`Language`][Language]
     ///
     /// [Language]: ../language/struct.Language.html
@@ -276,6 +679,35 @@ impl Mnemonic {
     }
 }
 
+/// Color a line of [`Mnemonic::to_colored_display`], alternating between two
+/// colors by group of words.
+#[cfg(feature = "colors")]
+fn color_line(line: &str, group: usize) -> String {
+    use colored::Colorize;
+
+    match group % 2 {
+        0 => line.cyan(),
+        _ => line.yellow(),
+    }
+    .to_string()
+}
+
+/// Without the `colors` feature, lines of [`Mnemonic::to_colored_display`]
+/// are plain text.
+#[cfg(not(feature = "colors"))]
+fn color_line(line: &str, _group: usize) -> String {
+    line.to_string()
+}
+
+/// Zero a secret buffer, then fill its whole capacity with random bytes, for
+/// [`Mnemonic::secure_delete`].
+fn wipe(buffer: &mut Vec<u8>) {
+    buffer.zeroize();
+    // Stays within the existing allocation, so the secret is never copied
+    buffer.resize(buffer.capacity(), 0);
+    fill_random_bytes(buffer);
+}
+
 impl AsRef<str> for Mnemonic {
     fn as_ref(&self) -> &str {
         self.phrase()
@@ -375,6 +807,261 @@ mod test {
         assert_eq!(entropy, mnemonic.entropy());
     }
 
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_phrase_bytes() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(phrase.as_bytes(), mnemonic.phrase_bytes());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_into_zeroized_phrase() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(mnemonic.into_zeroized_phrase().as_str(), phrase);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_secure_delete() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+
+        let mut buffer = Vec::with_capacity(128);
+        buffer.extend_from_slice(phrase.as_bytes());
+        let allocation = buffer.as_ptr();
+
+        wipe(&mut buffer);
+
+        // The whole allocation is overwritten in place, with no trace of the
+        // phrase and no leftover zeroed tail
+        assert_eq!(buffer.as_ptr(), allocation);
+        assert_eq!(buffer.len(), buffer.capacity());
+        assert!(!buffer.windows(4).any(|window| window == b"crop"));
+        assert!(buffer[phrase.len()..].iter().any(|&byte| byte != 0));
+
+        Mnemonic::from_phrase(phrase, Language::English)
+            .unwrap()
+            .secure_delete();
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_numeric_phrase() {
+        let phrase = "park remain person kitchen mule spell knee armed position rail grid ankle";
+        let numeric = "128214521306098411621673098800941347141508190073";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(mnemonic.to_numeric_phrase(), numeric);
+        assert_eq!(
+            Mnemonic::from_numeric_phrase(numeric, Language::English)
+                .unwrap()
+                .phrase(),
+            phrase
+        );
+
+        // SeedSigner SeedQR example
+        let numeric = "011513251154012711900771041507421289190620080870026613431420201617920614089619290300152408010643";
+        let mnemonic = Mnemonic::from_numeric_phrase(numeric, Language::English).unwrap();
+        assert_eq!(
+            mnemonic.phrase(),
+            "attack pizza motion avocado network gather crop fresh patrol unusual wild holiday \
+             candy pony ranch winter theme error hybrid van cereal salon goddess expire"
+        );
+
+        // Truncated, out of range, not a number and bad checksum
+        assert!(Mnemonic::from_numeric_phrase(&numeric[..95], Language::English).is_err());
+        assert!(Mnemonic::from_numeric_phrase("2048", Language::English).is_err());
+        assert!(Mnemonic::from_numeric_phrase("+115", Language::English).is_err());
+        assert!(Mnemonic::from_numeric_phrase(&numeric[..92], Language::English).is_err());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_from_bip85_index() {
+        // BIP85 test vectors
+        let root: XPrv = "xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb".parse().unwrap();
+        let child = |words| {
+            Mnemonic::from_bip85_index(&root, Language::English, words, 0)
+                .unwrap()
+                .into_phrase()
+        };
+
+        assert_eq!(
+            child(MnemonicType::Words12),
+            "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose"
+        );
+        assert_eq!(
+            child(MnemonicType::Words18),
+            "near account window bike charge season chef number sketch tomorrow excuse sniff \
+             circle vital hockey outdoor supply token"
+        );
+        assert_eq!(
+            child(MnemonicType::Words24),
+            "puppy ocean match cereal symbol another shed magic wrap hammer bulb intact gadget \
+             divorce twin tonight reason outdoor destroy simple truth cigar social volcano"
+        );
+
+        assert_ne!(
+            Mnemonic::from_bip85_index(&root, Language::English, MnemonicType::Words12, 1)
+                .unwrap()
+                .phrase(),
+            child(MnemonicType::Words12)
+        );
+        assert!(Mnemonic::from_bip85_index(
+            &root,
+            Language::English,
+            MnemonicType::Words12,
+            1 << 31
+        )
+        .is_err());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_weak_phrases() {
+        let is_weak = |phrase: &str| {
+            let err = Mnemonic::validate_against_known_weak_phrases(phrase, Language::English)
+                .unwrap_err();
+            matches!(
+                err.downcast_ref::<ErrorKind>(),
+                Some(ErrorKind::WeakEntropy)
+            )
+        };
+
+        assert!(is_weak("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"));
+        assert!(is_weak("zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong"));
+        assert!(is_weak(
+            "legal winner thank year wave sausage worth useful legal winner thank yellow"
+        ));
+
+        let sequential: Vec<u8> = (0..16).collect();
+        let mnemonic = Mnemonic::from_entropy(&sequential, Language::English).unwrap();
+        assert!(is_weak(mnemonic.phrase()));
+
+        let descending: Vec<u8> = (0..32).rev().collect();
+        let mnemonic = Mnemonic::from_entropy(&descending, Language::English).unwrap();
+        assert!(is_weak(mnemonic.phrase()));
+
+        assert!(Mnemonic::validate_against_known_weak_phrases(
+            "crop cash unable insane eight faith inflict route frame loud box vibrant",
+            Language::English
+        )
+        .is_ok());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_to_seed_array() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+
+        let seed = mnemonic.to_seed_array();
+
+        assert_eq!(&seed[..], Seed::new(&mnemonic, "").as_bytes());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_validate_with_stats() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let stats = Mnemonic::validate_with_stats(phrase, Language::English).unwrap();
+        assert_eq!(
+            stats,
+            MnemonicValidationStats {
+                word_count: 12,
+                normalized_words: 0,
+                checksum_valid: true,
+            }
+        );
+
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box box";
+        let stats = Mnemonic::validate_with_stats(phrase, Language::English).unwrap();
+        assert!(!stats.checksum_valid);
+
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box";
+        assert!(Mnemonic::validate_with_stats(phrase, Language::English).is_err());
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(not(feature = "colors"))]
+    fn mnemonic_colored_display_plain() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(
+            mnemonic.to_colored_display(),
+            " 1. crop      2. cash      3. unable    4. insane\n \
+             5. eight     6. faith     7. inflict   8. route\n \
+             9. frame    10. loud     11. box      12. vibrant"
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg(feature = "colors")]
+    fn mnemonic_colored_display_colors() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        colored::control::set_override(true);
+        assert_eq!(
+            mnemonic.to_colored_display(),
+            "\x1b[36m 1. crop      2. cash      3. unable    4. insane\x1b[0m\n\
+             \x1b[33m 5. eight     6. faith     7. inflict   8. route\x1b[0m\n\
+             \x1b[36m 9. frame    10. loud     11. box      12. vibrant\x1b[0m"
+        );
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_numbered_grid() {
+        let phrase = "crop cash unable insane eight faith inflict route frame loud box vibrant";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+
+        assert_eq!(
+            mnemonic.as_numbered_grid(3),
+            " 1. crop      5. eight     9. frame\n \
+             2. cash      6. faith    10. loud\n \
+             3. unable    7. inflict  11. box\n \
+             4. insane    8. route    12. vibrant"
+        );
+        assert_eq!(
+            mnemonic.as_numbered_grid(5),
+            " 1. crop      4. insane    7. inflict   9. frame    11. box\n \
+             2. cash      5. eight     8. route    10. loud     12. vibrant\n \
+             3. unable    6. faith"
+        );
+        assert_eq!(mnemonic.as_numbered_grid(13), mnemonic.as_numbered_grid(12));
+        assert_eq!(mnemonic.as_numbered_grid(12).lines().count(), 1);
+        assert_eq!(mnemonic.as_numbered_grid(0), mnemonic.as_numbered_grid(1));
+        assert_eq!(mnemonic.as_numbered_grid(1).lines().count(), 12);
+    }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn mnemonic_from_entropy_with_bad_checksum() {
+        for entropy in [&[0x33u8; 16][..], &[0x5au8; 32][..]] {
+            let mnemonic =
+                unsafe { Mnemonic::from_entropy_with_bad_checksum(entropy, Language::English) };
+            let valid = Mnemonic::from_entropy(entropy, Language::English).unwrap();
+
+            assert_ne!(mnemonic.phrase(), valid.phrase());
+            assert_eq!(mnemonic.entropy(), valid.entropy());
+
+            let err = Mnemonic::validate(mnemonic.phrase(), Language::English).unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<ErrorKind>(),
+                Some(ErrorKind::InvalidChecksum)
+            ));
+        }
+    }
+
     #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
     #[cfg_attr(not(target_arch = "wasm32"), test)]
     fn mnemonic_format() {
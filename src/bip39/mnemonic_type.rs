@@ -194,6 +194,24 @@ impl fmt::Display for MnemonicType {
     }
 }
 
+impl TryFrom<u8> for MnemonicType {
+    type Error = Error;
+
+    /// Get a `MnemonicType` from its word count, see [`MnemonicType::for_word_count`][MnemonicType::for_word_count()]
+    ///
+    /// [MnemonicType::for_word_count()]: ./enum.MnemonicType.html#method.for_word_count
+    fn try_from(word_count: u8) -> Result<MnemonicType, Error> {
+        Self::for_word_count(word_count as usize)
+    }
+}
+
+impl From<MnemonicType> for u8 {
+    /// Get the word count of a `MnemonicType`
+    fn from(mnemonic_type: MnemonicType) -> u8 {
+        mnemonic_type.word_count() as u8
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -229,4 +247,15 @@ mod test {
         assert_eq!(MnemonicType::Words21.checksum_bits(), 7);
         assert_eq!(MnemonicType::Words24.checksum_bits(), 8);
     }
+
+    #[cfg_attr(all(target_arch = "wasm32"), wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn u8_round_trip() {
+        for word_count in [12u8, 15, 18, 21, 24] {
+            let mnemonic_type = MnemonicType::try_from(word_count).unwrap();
+            assert_eq!(u8::from(mnemonic_type), word_count);
+        }
+
+        assert!(MnemonicType::try_from(13u8).is_err());
+    }
 }
@@ -4,11 +4,13 @@ mod language;
 mod mnemonic;
 mod mnemonic_type;
 mod seed;
+#[cfg(feature = "test-vectors")]
+mod test_vectors;
 mod util;
 
 pub use error::ErrorKind;
 pub use language::Language;
-pub use mnemonic::Mnemonic;
+pub use mnemonic::{Mnemonic, MnemonicValidationStats};
 pub use mnemonic_type::MnemonicType;
 pub use seed::Seed;
 
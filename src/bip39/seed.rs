@@ -1,5 +1,6 @@
-use super::crypto::pbkdf2;
-use super::Mnemonic;
+use super::crypto::{pbkdf2, PBKDF2_ROUNDS};
+use super::{Language, Mnemonic};
+use crate::bip32::{self, DerivationPath, XPrv};
 use std::fmt;
 use unicode_normalization::UnicodeNormalization;
 use zeroize::Zeroize;
@@ -23,6 +24,7 @@ use zeroize::Zeroize;
 #[derive(Clone)]
 pub struct Seed {
     bytes: Vec<u8>,
+    iterations: u32,
 }
 
 impl Seed {
@@ -34,13 +36,46 @@ impl Seed {
         let normalized_salt = salt.nfkd().to_string();
         let bytes = pbkdf2(&mnemonic.as_bytes(), &normalized_salt);
 
-        Self { bytes }
+        Self {
+            bytes,
+            iterations: PBKDF2_ROUNDS,
+        }
     }
 
     /// Get the seed value as a byte slice
     pub fn as_bytes(&self) -> &[u8] {
         &self.bytes
     }
+
+    /// Get the number of PBKDF2 iterations used to generate the seed
+    ///
+    /// This is always 2048 for seeds generated by [`Seed::new()`][Seed::new()], as specified by
+    /// BIP39, and is exposed so backup formats can record it alongside the mnemonic.
+    ///
+    /// [Seed::new()]: ./seed/struct.Seed.html#method.new
+    pub fn pbkdf2_iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Derive the BIP32 extended private key at `path` from this seed.
+    ///
+    /// Shorthand for [`XPrv::new_from_path()`][XPrv::new_from_path()].
+    ///
+    /// [XPrv::new_from_path()]: ../bip32/type.XPrv.html
+    pub fn derive_child_seed(&self, path: &DerivationPath) -> Result<XPrv, bip32::Error> {
+        XPrv::new_from_path(self, path)
+    }
+
+    /// Re-encode the first 32 bytes of the seed as a 24-word phrase, for backup display only.
+    ///
+    /// Deriving a seed from a mnemonic is one-way, so this is **not** the mnemonic the seed was
+    /// generated from, and running [`Seed::new()`][Seed::new()] on the result yields a different
+    /// seed. The second half of the seed is not represented in the phrase at all.
+    ///
+    /// [Seed::new()]: ./seed/struct.Seed.html#method.new
+    pub fn to_display_mnemonic(&self, lang: Language) -> Mnemonic {
+        Mnemonic::from_entropy(&self.bytes[..32], lang).expect("32 bytes is a valid entropy length")
+    }
 }
 
 impl AsRef<[u8]> for Seed {
@@ -92,7 +127,7 @@ impl Drop for Seed {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::bip39::Language;
+    use crate::bip39::MnemonicType;
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::*;
 
@@ -112,6 +147,38 @@ mod test {
         assert_eq!(format!("{:#X}", seed), "0x0BDE96F14C35A66235478E0C16C152FCAF6301E4D9A81D3FEBC50879FE7E5438E6A8DD3E39BDF3AB7B12D6B44218710E17D7A2844EE9633FAB0E03D9A6C8569B");
     }
 
+    #[test]
+    fn seed_pbkdf2_iterations() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+        let seed = Seed::new(&mnemonic, "");
+
+        assert_eq!(seed.pbkdf2_iterations(), 2048);
+    }
+
+    #[test]
+    fn seed_to_display_mnemonic() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+        let seed = Seed::new(&mnemonic, "");
+
+        let display = seed.to_display_mnemonic(Language::English);
+
+        assert_eq!(display.phrase().split(' ').count(), 24);
+        assert_eq!(display.entropy(), &seed.as_bytes()[..32]);
+        assert_ne!(display.phrase(), mnemonic.phrase());
+    }
+
+    #[test]
+    fn seed_derive_child_seed() {
+        let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+        let seed = Seed::new(&mnemonic, "");
+        let path: DerivationPath = "m/44'/0'/0'/0/0".parse().unwrap();
+
+        assert_eq!(
+            seed.derive_child_seed(&path).unwrap(),
+            XPrv::new_from_path(&seed, &path).unwrap()
+        );
+    }
+
     fn test_unicode_normalization(
         lang: Language,
         phrase: &str,
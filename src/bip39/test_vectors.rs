@@ -0,0 +1,120 @@
+//! Parser for the BIP39 test vector file published by Trezor, for conformance checking in CI.
+//!
+//! The file is a JSON object mapping a language name to a list of vectors, each a list of the
+//! entropy, phrase, seed and master `xprv`, all generated with the passphrase `TREZOR`:
+//!
+//! ```json
+//! { "english": [ ["00000000...", "abandon ... about", "c55257c3...", "xprv9s21..."] ] }
+//! ```
+
+use super::{Language, Mnemonic, Seed};
+use crate::bip32::{Prefix, XPrv};
+use anyhow::{anyhow, bail, Error};
+use std::collections::BTreeMap;
+
+/// Passphrase used for every vector in the file.
+const PASSPHRASE: &str = "TREZOR";
+
+impl Mnemonic {
+    /// Parse the BIP39 JSON test vector file and check every vector against this crate
+    ///
+    /// Each vector's phrase is parsed and its entropy, seed and master `xprv` are compared
+    /// against the expected values. Returns the parsed `(Mnemonic, Seed)` pairs, or an error
+    /// describing the first malformed or mismatching vector.
+    ///
+    /// Only the `english` vectors are supported.
+    pub fn from_standard_bip39_test_json(json: &str) -> Result<Vec<(Mnemonic, Seed)>, Error> {
+        let languages: BTreeMap<String, Vec<Vec<String>>> = serde_json::from_str(json)
+            .map_err(|err| anyhow!("malformed test vector JSON: {}", err))?;
+
+        let mut results = Vec::new();
+
+        for (name, vectors) in languages {
+            let lang = match name.as_str() {
+                "english" => Language::English,
+                _ => bail!("unsupported test vector language: {}", name),
+            };
+
+            for vector in vectors {
+                results.push(check_vector(&vector, lang, results.len())?);
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// Check one `[entropy, phrase, seed, xprv]` vector.
+fn check_vector(
+    vector: &[String],
+    lang: Language,
+    index: usize,
+) -> Result<(Mnemonic, Seed), Error> {
+    let [entropy, phrase, seed_hex, xprv] = vector else {
+        bail!("test vector {} does not have 4 fields", index);
+    };
+
+    let mnemonic = Mnemonic::from_phrase(phrase, lang)?;
+    if hex::encode(mnemonic.entropy()) != *entropy {
+        bail!("test vector {}: entropy mismatch", index);
+    }
+
+    let seed = Seed::new(&mnemonic, PASSPHRASE);
+    if format!("{:x}", seed) != *seed_hex {
+        bail!("test vector {}: seed mismatch", index);
+    }
+
+    let master = XPrv::new(&seed).map_err(|err| anyhow!("test vector {}: {}", index, err))?;
+    if *master.to_string(Prefix::XPRV) != *xprv {
+        bail!("test vector {}: xprv mismatch", index);
+    }
+
+    Ok((mnemonic, seed))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const JSON: &str = r#"{
+        "english": [
+            [
+                "00000000000000000000000000000000",
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+                "xprv9s21ZrQH143K3h3fDYiay8mocZ3afhfULfb5GX8kCBdno77K4HiA15Tg23wpbeF1pLfs1c5SPmYHrEpTuuRhxMwvKDwqdKiGJS9XFKzUsAF"
+            ],
+            [
+                "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+                "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+                "xprv9s21ZrQH143K2gA81bYFHqU68xz1cX2APaSq5tt6MFSLeXnCKV1RVUJt9FWNTbrrryem4ZckN8k4Ls1H6nwdvDTvnV7zEXs2HgPezuVccsq"
+            ]
+        ]
+    }"#;
+
+    #[test]
+    fn parses_standard_vectors() {
+        let vectors = Mnemonic::from_standard_bip39_test_json(JSON).unwrap();
+
+        assert_eq!(vectors.len(), 2);
+        assert_eq!(
+            vectors[1].0.phrase(),
+            "legal winner thank year wave sausage worth useful legal winner thank yellow"
+        );
+    }
+
+    #[test]
+    fn rejects_mismatching_vectors() {
+        let json = JSON.replacen("c55257c3", "c55257c4", 1);
+
+        let err = Mnemonic::from_standard_bip39_test_json(&json).unwrap_err();
+        assert_eq!(err.to_string(), "test vector 0: seed mismatch");
+
+        let json = JSON.replacen("english", "klingon", 1);
+        assert!(Mnemonic::from_standard_bip39_test_json(&json).is_err());
+
+        let json = JSON.replacen("]\n        ]", "]", 1);
+        assert!(Mnemonic::from_standard_bip39_test_json(&json).is_err());
+    }
+}
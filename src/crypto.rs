@@ -1,4 +1,8 @@
+use crate::error::Error;
 use hex;
+use hmac::Hmac;
+#[cfg(feature = "debug-rfc6979")]
+use hmac::Mac;
 use ripemd::{Digest, Ripemd160};
 
 pub fn ripemd(msg: &[u8]) -> String {
@@ -12,3 +16,350 @@ pub fn ripemd(msg: &[u8]) -> String {
     let result = hasher.finalize();
     hex::encode(&result[..])
 }
+
+/// Keccak-f[1600] round constants.
+const KECCAK_ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Keccak-f[1600] rotation offsets, in the lane order visited by `KECCAK_PI`.
+const KECCAK_RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// Keccak-f[1600] lane permutation.
+const KECCAK_PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Keccak-f[1600] permutation.
+fn keccak_f(state: &mut [u64; 25]) {
+    for round_constant in KECCAK_ROUND_CONSTANTS {
+        // θ
+        let mut columns = [0u64; 5];
+        for (x, column) in columns.iter_mut().enumerate() {
+            *column = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = columns[(x + 4) % 5] ^ columns[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[5 * y + x] ^= d;
+            }
+        }
+
+        // ρ and π
+        let mut last = state[1];
+        for (pi, rho) in KECCAK_PI.iter().zip(KECCAK_RHO) {
+            let next = state[*pi];
+            state[*pi] = last.rotate_left(rho);
+            last = next;
+        }
+
+        // χ
+        for row in state.chunks_exact_mut(5) {
+            let lanes = [row[0], row[1], row[2], row[3], row[4]];
+            for (x, lane) in row.iter_mut().enumerate() {
+                *lane = lanes[x] ^ (!lanes[(x + 1) % 5] & lanes[(x + 2) % 5]);
+            }
+        }
+
+        // ι
+        state[0] ^= round_constant;
+    }
+}
+
+/// Keccak-256 as used by Ethereum.
+///
+/// This is the original Keccak submission, which pads differently from the
+/// standardized SHA3-256 and so produces different digests.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    const RATE: usize = 136;
+
+    let mut state = [0u64; 25];
+    let absorb = |state: &mut [u64; 25], block: &[u8]| {
+        for (lane, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+            *lane ^= u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        keccak_f(state);
+    };
+
+    let mut blocks = data.chunks_exact(RATE);
+    for block in &mut blocks {
+        absorb(&mut state, block);
+    }
+
+    let remainder = blocks.remainder();
+    let mut last = [0u8; RATE];
+    last[..remainder.len()].copy_from_slice(remainder);
+    last[remainder.len()] ^= 0x01;
+    last[RATE - 1] ^= 0x80;
+    absorb(&mut state, &last);
+
+    let mut digest = [0u8; 32];
+    for (bytes, lane) in digest.chunks_exact_mut(8).zip(&state) {
+        bytes.copy_from_slice(&lane.to_le_bytes());
+    }
+    digest
+}
+
+/// CRC-32 (IEEE 802.3) checksum, as used by UR and passphrase hints.
+///
+/// Not a cryptographic hash: it only detects accidental corruption.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    // reflected polynomial
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+/// PBKDF2-HMAC-SHA256 (RFC 8018), filling `output` with the derived key.
+///
+/// Meant for formats such as PKCS#8 that use the SHA-256 variant. BIP39 seed
+/// generation uses PBKDF2-HMAC-SHA512 and must not use this function.
+pub fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], iterations: u32, output: &mut [u8]) {
+    pbkdf2::pbkdf2::<Hmac<sha2::Sha256>>(password, salt, iterations, output);
+}
+
+/// Multiply a secp256k1 public key by a scalar, e.g. for ECDH or key tweaking.
+///
+/// Returns [`Error::Crypto`] if the scalar is zero or not below the curve order.
+pub fn secp256k1_mul_scalar(
+    pubkey: &libsecp256k1::PublicKey,
+    scalar: &[u8; 32],
+) -> Result<libsecp256k1::PublicKey, Error> {
+    let tweak = libsecp256k1::SecretKey::parse(scalar).map_err(|_| Error::Crypto)?;
+    let mut result = *pubkey;
+    result.tweak_mul_assign(&tweak).map_err(|_| Error::Crypto)?;
+    Ok(result)
+}
+
+/// Negate a secp256k1 private key, i.e. compute `n - sk` where `n` is the
+/// curve order, e.g. for adaptor signatures and Schnorr tweaks.
+pub fn secp256k1_negate_scalar(sk: &libsecp256k1::SecretKey) -> libsecp256k1::SecretKey {
+    let scalar: libsecp256k1::curve::Scalar = (*sk).into();
+    libsecp256k1::SecretKey::try_from(-scalar).expect("negated private key is non-zero")
+}
+
+/// Add secp256k1 public keys together, e.g. to aggregate keys for MuSig.
+///
+/// Returns [`Error::Crypto`] if `keys` is empty or the sum is the point at
+/// infinity.
+pub fn secp256k1_public_key_combine(
+    keys: &[libsecp256k1::PublicKey],
+) -> Result<libsecp256k1::PublicKey, Error> {
+    // an empty sum is the point at infinity, so it is rejected too
+    libsecp256k1::PublicKey::combine(keys).map_err(|_| Error::Crypto)
+}
+
+/// Sign a message hash, also returning the RFC 6979 nonce `k` it was signed
+/// with, for checking other implementations and generating test vectors.
+///
+/// The signature is the same as the one produced by [`libsecp256k1::sign`],
+/// serialized as `r || s`.
+///
+/// **Warning:** anyone who learns the nonce of a signature can recover the
+/// private key from it. Never use this outside of tests.
+#[cfg(feature = "debug-rfc6979")]
+pub fn secp256k1_sign_rfc6979_nonce_debug(
+    sk: &libsecp256k1::SecretKey,
+    msg: &[u8; 32],
+) -> ([u8; 32], [u8; 64]) {
+    type HmacSha256 = Hmac<sha2::Sha256>;
+
+    let hmac = |key: &[u8], parts: &[&[u8]]| -> [u8; 32] {
+        let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key size");
+        for part in parts {
+            mac.update(part);
+        }
+        mac.finalize().into_bytes().into()
+    };
+
+    let message = libsecp256k1::Message::parse(msg);
+    let x = sk.serialize();
+    let h1 = message.0.b32();
+
+    // HMAC-DRBG instantiation (RFC 6979 section 3.2, steps b-g)
+    let mut v = [0x01u8; 32];
+    let mut k = [0x00u8; 32];
+    k = hmac(&k, &[&v, &[0x00], &x, &h1]);
+    v = hmac(&k, &[&v]);
+    k = hmac(&k, &[&v, &[0x01], &x, &h1]);
+    v = hmac(&k, &[&v]);
+
+    // Generate candidates until one is a valid scalar (step h)
+    let nonce = loop {
+        v = hmac(&k, &[&v]);
+
+        if libsecp256k1::SecretKey::parse(&v).is_ok() {
+            break v;
+        }
+
+        k = hmac(&k, &[&v, &[0x00]]);
+        v = hmac(&k, &[&v]);
+    };
+
+    let (signature, _) = libsecp256k1::sign(&message, sk);
+    (nonce, signature.serialize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keccak256_vectors() {
+        assert_eq!(
+            hex::encode(keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(keccak256(b"abc")),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+    }
+
+    #[test]
+    fn pbkdf2_hmac_sha256_vectors() {
+        // RFC 6070 inputs, with the well-known PBKDF2-HMAC-SHA256 outputs
+        let mut output = [0u8; 32];
+        pbkdf2_hmac_sha256(b"password", b"salt", 1, &mut output);
+        assert_eq!(
+            hex::encode(output),
+            "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b"
+        );
+
+        pbkdf2_hmac_sha256(b"password", b"salt", 4096, &mut output);
+        assert_eq!(
+            hex::encode(output),
+            "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"
+        );
+    }
+
+    #[test]
+    fn secp256k1_mul_scalar_matches_base_point() {
+        let one = libsecp256k1::SecretKey::default();
+        let generator = libsecp256k1::PublicKey::from_secret_key(&one);
+        let scalar = [0x11u8; 32];
+
+        let product = secp256k1_mul_scalar(&generator, &scalar).unwrap();
+        let expected = libsecp256k1::PublicKey::from_secret_key(
+            &libsecp256k1::SecretKey::parse(&scalar).unwrap(),
+        );
+        assert_eq!(product, expected);
+
+        assert!(matches!(
+            secp256k1_mul_scalar(&generator, &[0u8; 32]),
+            Err(Error::Crypto)
+        ));
+        assert!(matches!(
+            secp256k1_mul_scalar(&generator, &[0xffu8; 32]),
+            Err(Error::Crypto)
+        ));
+    }
+
+    #[test]
+    fn secp256k1_negate_scalar_sums_to_zero() {
+        let sk = libsecp256k1::SecretKey::parse(&[0x11u8; 32]).unwrap();
+        let negated = secp256k1_negate_scalar(&sk);
+
+        assert_ne!(negated, sk);
+        assert_eq!(secp256k1_negate_scalar(&negated), sk);
+
+        // sk + (n - sk) = 0, which is not a valid private key
+        let mut sum = sk;
+        assert_eq!(
+            sum.tweak_add_assign(&negated),
+            Err(libsecp256k1::Error::TweakOutOfRange)
+        );
+    }
+
+    #[test]
+    fn secp256k1_public_key_combine_sums_points() {
+        let a = libsecp256k1::SecretKey::parse(&[0x11u8; 32]).unwrap();
+        let b = libsecp256k1::SecretKey::parse(&[0x22u8; 32]).unwrap();
+        let pubkey = libsecp256k1::PublicKey::from_secret_key;
+
+        let mut sum = a;
+        sum.tweak_add_assign(&b).unwrap();
+        assert_eq!(
+            secp256k1_public_key_combine(&[pubkey(&a), pubkey(&b)]).unwrap(),
+            pubkey(&sum)
+        );
+
+        let mut double = a;
+        double.tweak_add_assign(&a).unwrap();
+        assert_eq!(
+            secp256k1_public_key_combine(&[pubkey(&a), pubkey(&a)]).unwrap(),
+            pubkey(&double)
+        );
+
+        assert!(matches!(
+            secp256k1_public_key_combine(&[]),
+            Err(Error::Crypto)
+        ));
+        assert!(matches!(
+            secp256k1_public_key_combine(&[pubkey(&a), pubkey(&secp256k1_negate_scalar(&a))]),
+            Err(Error::Crypto)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "debug-rfc6979")]
+    fn secp256k1_sign_rfc6979_nonce_debug_vector() {
+        use sha2::Sha256;
+
+        // Widely used secp256k1 RFC 6979 vector: key 1, SHA-256("Satoshi Nakamoto")
+        let mut sk = [0u8; 32];
+        sk[31] = 1;
+        let sk = libsecp256k1::SecretKey::parse(&sk).unwrap();
+        let msg: [u8; 32] = Sha256::digest(b"Satoshi Nakamoto").into();
+
+        let (nonce, signature) = secp256k1_sign_rfc6979_nonce_debug(&sk, &msg);
+        assert_eq!(
+            hex::encode(nonce),
+            "8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15"
+        );
+        assert_eq!(
+            hex::encode(&signature[..32]),
+            "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8"
+        );
+
+        // r is the x coordinate of k * G
+        let r = libsecp256k1::PublicKey::from_secret_key(
+            &libsecp256k1::SecretKey::parse(&nonce).unwrap(),
+        );
+        assert_eq!(&r.serialize_compressed()[1..], &signature[..32]);
+    }
+}
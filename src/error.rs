@@ -2,4 +2,6 @@
 pub enum Error {
     #[error("secp256k1 error")]
     Secp256k1Error(#[from] libsecp256k1::Error),
+    #[error("cryptographic error")]
+    Crypto,
 }